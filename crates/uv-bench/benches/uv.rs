@@ -30,6 +30,14 @@ const MANY_FILES_WHEEL_FILE_COUNT: usize = 10_000;
 const MANY_FILES_SDIST_FILENAME: &str = "manyfiles-0.0.0.tar.gz";
 const MANY_FILES_SDIST_TOP_LEVEL: &str = "manyfiles-0.0.0";
 const MANY_FILES_SDIST_FILE_COUNT: usize = 10_000;
+#[cfg(target_os = "macos")]
+const MIXED_FILES_WHEEL_FILENAME: &str = "mixedfiles-0.0.0-cp311-cp311-macosx_11_0_arm64.whl";
+#[cfg(target_os = "macos")]
+const MIXED_FILES_WHEEL_SOURCE_COUNT: usize = 2_000;
+#[cfg(target_os = "macos")]
+const MIXED_FILES_WHEEL_BINARY_COUNT: usize = 50;
+#[cfg(target_os = "macos")]
+const MIXED_FILES_WHEEL_BINARY_SIZE: usize = 1024 * 1024;
 
 fn create_many_files_wheel() -> tempfile::NamedTempFile {
     let archive = tempfile::NamedTempFile::new().expect("Failed to create temporary archive");
@@ -66,6 +74,58 @@ fn create_many_files_wheel() -> tempfile::NamedTempFile {
     archive
 }
 
+/// Create a wheel with a mix of Python sources and (synthetic) Mach-O shared libraries, as is
+/// typical for packages with native extensions.
+#[cfg(target_os = "macos")]
+fn create_mixed_files_wheel() -> tempfile::NamedTempFile {
+    // The 64-bit Mach-O magic bytes, followed by padding up to a realistic library size.
+    let mut binary = vec![0xcf, 0xfa, 0xed, 0xfe];
+    binary.resize(MIXED_FILES_WHEEL_BINARY_SIZE, 0);
+
+    let archive = tempfile::NamedTempFile::new().expect("Failed to create temporary archive");
+    let mut writer = ZipFileWriter::new(Vec::new());
+    let mut record = String::new();
+    for index in 0..MIXED_FILES_WHEEL_SOURCE_COUNT {
+        let path = format!("mixedfiles/module_{index}.py");
+        write_zip_entry(&mut writer, &path, b"def f():\n    return 1\n");
+        writeln!(record, "{path},,").expect("Writing to a string cannot fail");
+    }
+    for index in 0..MIXED_FILES_WHEEL_BINARY_COUNT {
+        let extension = if index % 2 == 0 {
+            "cpython-311-darwin.so"
+        } else {
+            "dylib"
+        };
+        let path = format!("mixedfiles/_native_{index}.{extension}");
+        write_zip_entry(&mut writer, &path, &binary);
+        writeln!(record, "{path},,").expect("Writing to a string cannot fail");
+    }
+    write_zip_entry(
+        &mut writer,
+        "mixedfiles-0.0.0.dist-info/METADATA",
+        b"Metadata-Version: 2.1\nName: mixedfiles\nVersion: 0.0.0\n",
+    );
+    write_zip_entry(
+        &mut writer,
+        "mixedfiles-0.0.0.dist-info/WHEEL",
+        b"Wheel-Version: 1.0\nGenerator: uv-bench\nRoot-Is-Purelib: false\nTag: cp311-cp311-macosx_11_0_arm64\n",
+    );
+    record.push_str("mixedfiles-0.0.0.dist-info/METADATA,,\n");
+    record.push_str("mixedfiles-0.0.0.dist-info/WHEEL,,\n");
+    record.push_str("mixedfiles-0.0.0.dist-info/RECORD,,\n");
+    write_zip_entry(
+        &mut writer,
+        "mixedfiles-0.0.0.dist-info/RECORD",
+        record.as_bytes(),
+    );
+    fs_err::write(
+        archive.path(),
+        block_on(writer.close()).expect("Failed to finish ZIP archive"),
+    )
+    .expect("Failed to write temporary archive");
+    archive
+}
+
 fn create_many_files_sdist() -> tempfile::NamedTempFile {
     let archive = tempfile::NamedTempFile::new().expect("Failed to create temporary archive");
     let encoder = GzEncoder::new(archive.as_file(), flate2::Compression::default());
@@ -231,6 +291,57 @@ fn install_wheel_many_files(c: &mut Criterion<WallTime>) {
     });
 }
 
+/// Compare hard linking against cloning for a wheel that mixes native libraries and Python
+/// sources, to measure the cost and benefit of each strategy on APFS.
+#[cfg(target_os = "macos")]
+fn install_wheel_mixed_files(c: &mut Criterion<WallTime>) {
+    let archive = create_mixed_files_wheel();
+    let filename =
+        WheelFilename::from_str(MIXED_FILES_WHEEL_FILENAME).expect("Invalid wheel filename");
+    let extracted_wheel = tempfile::tempdir().expect("Failed to create wheel extraction directory");
+    prepare_wheel(
+        fs_err::File::open(archive.path()).expect("Failed to open temporary archive"),
+        extracted_wheel.path(),
+        &filename,
+    );
+
+    let mut group = c.benchmark_group("install_wheel_mixed_files");
+    for (name, link_mode) in [("hardlink", LinkMode::Hardlink), ("clone", LinkMode::Clone)] {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    let environment =
+                        tempfile::tempdir().expect("Failed to create installation directory");
+                    let layout = layout(environment.path());
+                    fs_err::create_dir_all(&layout.scheme.platlib)
+                        .expect("Failed to create site-packages directory");
+                    (environment, layout)
+                },
+                |(environment, layout)| {
+                    let state = InstallState::new(Preview::default());
+                    uv_install_wheel::install_wheel(
+                        &layout,
+                        false,
+                        extracted_wheel.path(),
+                        &filename,
+                        None,
+                        None::<&()>,
+                        None::<&()>,
+                        Some("uv"),
+                        true,
+                        link_mode,
+                        &state,
+                    )
+                    .expect("Failed to install wheel");
+                    black_box((environment, layout))
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
 fn prepare_wheel(
     archive: fs_err::File,
     extracted_wheel: &Path,
@@ -340,6 +451,17 @@ criterion_group! {
         resolve_warm_jupyter_universal,
         resolve_warm_airflow
 }
+
+#[cfg(target_os = "macos")]
+criterion_group! {
+    name = uv_macos;
+    config = Criterion::default();
+    targets = install_wheel_mixed_files
+}
+
+#[cfg(target_os = "macos")]
+criterion_main!(uv, uv_macos);
+#[cfg(not(target_os = "macos"))]
 criterion_main!(uv);
 
 fn setup(manifest: Manifest, universal: bool) -> impl Fn() {
@@ -1,17 +1,24 @@
 use std::fmt::{Display, Formatter};
 use std::io;
 use std::io::Write;
+use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
 use rustc_hash::FxHashMap;
 use tracing::{debug, trace, warn};
 
 use uv_cache_info::Timestamp;
-use uv_fs::{LockedFile, LockedFileError, LockedFileMode, Simplified, cachedir, directories};
+use uv_distribution_filename::WheelFilename;
+use uv_fs::{
+    LockedFile, LockedFileError, LockedFileMode, Simplified, cachedir, directories, entries,
+};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::ResolutionMetadata;
 
 pub use crate::by_timestamp::CachedByTimestamp;
@@ -267,6 +274,69 @@ impl Cache {
         }
     }
 
+    /// Acquire a lock that allows reading entries from the cache concurrently with other readers,
+    /// while blocking removal operations (e.g., `uv cache clean`).
+    ///
+    /// [`Cache::init`] already acquires this lock; this is intended for commands that read from
+    /// the cache without initializing it (e.g., `uv cache size`).
+    pub async fn with_shared_lock(self) -> Result<Self, LockedFileError> {
+        let Self {
+            root,
+            refresh,
+            temp_dir,
+            lock_file,
+        } = self;
+
+        // Release the existing lock, if any, before acquiring a new one.
+        drop(lock_file);
+        let lock_file = LockedFile::acquire(
+            root.join(".lock"),
+            LockedFileMode::Shared,
+            root.simplified_display(),
+        )
+        .await?;
+
+        Ok(Self {
+            root,
+            refresh,
+            temp_dir,
+            lock_file: Some(Arc::new(lock_file)),
+        })
+    }
+
+    /// Acquire a lock that allows reading entries from the cache concurrently with other readers,
+    /// if available.
+    ///
+    /// If the lock is not immediately available (e.g., because the cache is being cleaned), returns
+    /// [`Err`] with self.
+    pub fn with_shared_lock_no_wait(self) -> Result<Self, Self> {
+        let Self {
+            root,
+            refresh,
+            temp_dir,
+            lock_file,
+        } = self;
+
+        match LockedFile::acquire_no_wait(
+            root.join(".lock"),
+            LockedFileMode::Shared,
+            root.simplified_display(),
+        ) {
+            Some(lock_file) => Ok(Self {
+                root,
+                refresh,
+                temp_dir,
+                lock_file: Some(Arc::new(lock_file)),
+            }),
+            None => Err(Self {
+                root,
+                refresh,
+                temp_dir,
+                lock_file,
+            }),
+        }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -297,6 +367,54 @@ impl Cache {
         self.bucket(CacheBucket::Archive).join(id)
     }
 
+    /// Returns `true` if the given wheel is present in the cache, from any index or direct URL.
+    ///
+    /// Only the wheel's cache pointer is checked; the referenced archive is not validated.
+    pub fn contains_wheel(&self, filename: &WheelFilename) -> bool {
+        self.find_wheel(filename).is_some()
+    }
+
+    /// Returns how long ago the given wheel's cache entry was last accessed.
+    ///
+    /// Returns `None` if the wheel is not in the cache, or if the filesystem does not record
+    /// access times.
+    pub fn age_of(&self, filename: &WheelFilename) -> Option<Duration> {
+        let path = self.find_wheel(filename)?;
+        let accessed = fs_err::metadata(path).ok()?.accessed().ok()?;
+        Some(
+            SystemTime::now()
+                .duration_since(accessed)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Find the cache pointer for the given wheel, from any index or direct URL.
+    fn find_wheel(&self, filename: &WheelFilename) -> Option<PathBuf> {
+        let bucket = self.bucket(CacheBucket::Wheels);
+        let name = filename.name.to_string();
+        let key = filename.cache_key();
+
+        // For `pypi` wheels, we expect a directory per package (indexed by name). For alternate
+        // indices and direct URLs, we expect a directory for every index or URL, followed by a
+        // directory per package (indexed by name).
+        let roots = std::iter::once(bucket.join(WheelCacheKind::Pypi)).chain(
+            [WheelCacheKind::Index, WheelCacheKind::Url]
+                .into_iter()
+                .filter_map(|kind| directories(bucket.join(kind)).ok())
+                .flatten(),
+        );
+
+        roots
+            .map(|root| root.join(&name))
+            .flat_map(|directory| {
+                [
+                    directory.join(format!("{key}.http")),
+                    directory.join(format!("{key}.rev")),
+                ]
+            })
+            .find(|path| path.is_file())
+    }
+
     /// Create a temporary directory to be used as a Python virtual environment.
     pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
         fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
@@ -519,9 +637,15 @@ impl Cache {
     }
 
     /// Clear the cache, removing all entries.
-    pub fn clear(self, reporter: Box<dyn CleanReporter>) -> Result<Removal, io::Error> {
+    ///
+    /// Entries are removed across the given number of threads.
+    pub fn clear(
+        self,
+        reporter: Box<dyn CleanReporter>,
+        concurrency: NonZeroUsize,
+    ) -> Result<Removal, io::Error> {
         // Remove everything but `.lock`, Windows does not allow removal of a locked file
-        let mut removal = Remover::new(reporter).rm_rf(&self.root, true)?;
+        let mut removal = Remover::new(reporter).rm_rf_parallel(&self.root, true, concurrency)?;
         let Self {
             root, lock_file, ..
         } = self;
@@ -549,19 +673,139 @@ impl Cache {
         Ok(removal)
     }
 
+    /// Clear the cache, retaining the entries for the given packages.
+    ///
+    /// Entries that can't be attributed to a package (e.g., Git checkouts, cached environments,
+    /// or flat index metadata) are removed, along with any archives that are no longer referenced.
+    pub fn clear_except(&self, excluded: &[PackageName]) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+        for bucket in CacheBucket::iter() {
+            summary += bucket.remove_except(self, excluded)?;
+        }
+
+        // Remove any archives that were only referenced by the removed entries.
+        summary += self.remove_dangling_archives()?;
+
+        Ok(summary)
+    }
+
     /// Remove a package from the cache.
     ///
     /// Returns the number of entries removed from the cache.
     pub fn remove(&self, name: &PackageName) -> io::Result<Removal> {
+        self.remove_matching(name, None)
+    }
+
+    /// Remove a single version of a package from the cache.
+    ///
+    /// Unlike [`Cache::remove`], cache entries that are shared across versions of the package,
+    /// such as the index metadata, are retained.
+    pub fn remove_version(&self, name: &PackageName, version: &Version) -> io::Result<Removal> {
+        self.remove_matching(name, Some(version))
+    }
+
+    /// Remove a package from the cache, optionally restricted to a single version.
+    fn remove_matching(
+        &self,
+        name: &PackageName,
+        version: Option<&Version>,
+    ) -> io::Result<Removal> {
         // Collect the set of referenced archives.
         let references = self.find_archive_references()?;
 
         // Remove any entries for the package from the cache.
         let mut summary = Removal::default();
         for bucket in CacheBucket::iter() {
-            summary += bucket.remove(self, name)?;
+            summary += bucket.remove(self, name, version)?;
+        }
+
+        // Remove any archives that are no longer referenced.
+        summary += self.remove_unreferenced_archives(references)?;
+
+        Ok(summary)
+    }
+
+    /// Remove multiple packages from the cache in parallel, each optionally restricted to a single
+    /// version.
+    ///
+    /// The `on_remove` callback is invoked once per package, with the index of the package and the
+    /// running total of removed entries. The returned total matches that of removing each package
+    /// in turn via [`Cache::remove`] or [`Cache::remove_version`].
+    pub fn remove_packages<F>(
+        &self,
+        packages: &[(PackageName, Option<Version>)],
+        concurrency: NonZeroUsize,
+        on_remove: F,
+    ) -> io::Result<Removal>
+    where
+        F: Fn(usize, &Removal) + Sync,
+    {
+        // Collect the set of referenced archives.
+        let references = self.find_archive_references()?;
+
+        // The flat index can't be attributed to a package, so it's removed in its entirety (once)
+        // if any package is removed across all versions.
+        let mut summary = Removal::default();
+        if packages.iter().any(|(_, version)| version.is_none()) {
+            summary += rm_rf(self.bucket(CacheBucket::FlatIndex))?;
         }
 
+        // Requests for the same package share cache entries, so each package name is handled by
+        // a single worker.
+        let mut groups = Vec::<Vec<usize>>::new();
+        let mut group_by_name = FxHashMap::<&PackageName, usize>::default();
+        for (index, (name, _)) in packages.iter().enumerate() {
+            let group = *group_by_name.entry(name).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(index);
+        }
+
+        let summary = Mutex::new(summary);
+        let next = AtomicUsize::new(0);
+        let workers = concurrency.get().min(groups.len());
+        std::thread::scope(|scope| {
+            let handles = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        while let Some(group) = groups.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            for &index in group {
+                                let (name, version) = &packages[index];
+                                let mut removal = Removal::default();
+                                for bucket in CacheBucket::iter() {
+                                    if bucket != CacheBucket::FlatIndex {
+                                        removal += bucket.remove(self, name, version.as_ref())?;
+                                    }
+                                }
+
+                                let mut summary = summary.lock().unwrap();
+                                *summary += removal;
+                                on_remove(index, &summary);
+                            }
+                        }
+                        Ok::<_, io::Error>(())
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .try_for_each(|handle| handle.join().expect("cache removal thread panicked"))
+        })?;
+
+        // Remove any archives that are no longer referenced.
+        let mut summary = summary.into_inner().unwrap();
+        summary += self.remove_unreferenced_archives(references)?;
+
+        Ok(summary)
+    }
+
+    /// Remove any archives whose references have all been removed.
+    fn remove_unreferenced_archives(
+        &self,
+        references: FxHashMap<PathBuf, Vec<PathBuf>>,
+    ) -> io::Result<Removal> {
+        let mut summary = Removal::default();
         if references.is_empty() {
             return Ok(summary);
         }
@@ -570,7 +814,6 @@ impl Cache {
         // to paths outside the cache.
         let archive_root = fs_err::canonicalize(&self.root)?.join(CacheBucket::Archive.to_str());
 
-        // Remove any archives that are no longer referenced.
         for (target, references) in references {
             if target.starts_with(&archive_root) && references.iter().all(|path| !path.exists()) {
                 debug!("Removing dangling cache entry: {}", target.display());
@@ -690,6 +933,40 @@ impl Cache {
         }
 
         // Fourth, remove any unused archives (by searching for archives that are not symlinked).
+        summary += self.remove_dangling_archives()?;
+
+        Ok(summary)
+    }
+
+    /// Remove build artifacts from the cache, retaining downloaded wheels and index metadata.
+    ///
+    /// Removes source distributions (and any wheels built from them), Git checkouts, and
+    /// ephemeral environments, along with any archives that are no longer referenced.
+    ///
+    /// Returns the buckets that were removed, alongside the summary of the removal.
+    pub fn remove_build_artifacts(&self) -> io::Result<(Vec<CacheBucket>, Removal)> {
+        let mut buckets = Vec::new();
+        let mut summary = Removal::default();
+
+        for bucket in CacheBucket::iter().filter(|bucket| bucket.is_build_artifact()) {
+            let path = self.bucket(bucket);
+            if fs_err::symlink_metadata(&path).is_err() {
+                continue;
+            }
+            debug!("Removing cache bucket: {}", path.display());
+            summary += rm_rf(path)?;
+            buckets.push(bucket);
+        }
+
+        // Remove any archives that were only referenced by the removed buckets.
+        summary += self.remove_dangling_archives()?;
+
+        Ok((buckets, summary))
+    }
+
+    /// Remove any archives that are not referenced by an entry in another cache bucket.
+    fn remove_dangling_archives(&self) -> io::Result<Removal> {
+        let mut summary = Removal::default();
         let references = self.find_archive_references()?;
 
         match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
@@ -1218,19 +1495,48 @@ impl CacheBucket {
         }
     }
 
-    /// Remove a package from the cache bucket.
+    /// Remove a package from the cache bucket, optionally restricted to a single version.
     ///
     /// Returns the number of entries removed from the cache.
-    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+    fn remove(
+        self,
+        cache: &Cache,
+        name: &PackageName,
+        version: Option<&Version>,
+    ) -> Result<Removal, io::Error> {
         /// Returns `true` if the [`Path`] represents a built wheel for the given package.
-        fn is_match(path: &Path, name: &PackageName) -> bool {
+        fn is_match(path: &Path, name: &PackageName, version: Option<&Version>) -> bool {
             let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
                 return false;
             };
             let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
                 return false;
             };
-            metadata.name == *name
+            metadata.name == *name && version.is_none_or(|version| metadata.version == *version)
+        }
+
+        /// Returns `true` if the [`Path`] is a cache entry for the given version, i.e., its name is
+        /// the version (e.g., `1.26.4`) or starts with the version (e.g., `1.26.4-py3-none-any`).
+        fn is_version(path: &Path, version: &Version) -> bool {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.split('-').next())
+                .and_then(|prefix| Version::from_str(prefix).ok())
+                .is_some_and(|prefix| prefix == *version)
+        }
+
+        /// Remove the cache entries for the package in the given directory, indexed by name.
+        fn remove_package(directory: &Path, version: Option<&Version>) -> io::Result<Removal> {
+            let Some(version) = version else {
+                return rm_rf(directory);
+            };
+            let mut summary = Removal::default();
+            for entry in entries(directory)? {
+                if is_version(&entry, version) {
+                    summary += rm_rf(entry)?;
+                }
+            }
+            Ok(summary)
         }
 
         let mut summary = Removal::default();
@@ -1238,32 +1544,32 @@ impl CacheBucket {
             Self::Wheels => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                summary += remove_package(&root.join(name.to_string()), version)?;
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    summary += remove_package(&directory.join(name.to_string()), version)?;
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    summary += remove_package(&directory.join(name.to_string()), version)?;
                 }
             }
             Self::SourceDistributions => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                summary += remove_package(&root.join(name.to_string()), version)?;
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    summary += remove_package(&directory.join(name.to_string()), version)?;
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
@@ -1271,7 +1577,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
-                    if directories(&url)?.any(|version| is_match(&version, name)) {
+                    if directories(&url)?.any(|version_dir| is_match(&version_dir, name, version)) {
                         summary += rm_rf(url)?;
                     }
                 }
@@ -1281,7 +1587,8 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
-                    if directories(&path)?.any(|version| is_match(&version, name)) {
+                    if directories(&path)?.any(|version_dir| is_match(&version_dir, name, version))
+                    {
                         summary += rm_rf(path)?;
                     }
                 }
@@ -1292,13 +1599,19 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Git);
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
-                        if is_match(&sha, name) {
+                        if is_match(&sha, name, version) {
                             summary += rm_rf(sha)?;
                         }
                     }
                 }
             }
             Self::Simple => {
+                // The index metadata is shared across all versions of the package, so retain it
+                // when removing a single version.
+                if version.is_some() {
+                    return Ok(summary);
+                }
+
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
                 summary += rm_rf(root.join(format!("{name}.rkyv")))?;
@@ -1311,6 +1624,12 @@ impl CacheBucket {
                 }
             }
             Self::FlatIndex => {
+                // As with the index metadata, the flat index is shared across all versions of the
+                // package, so retain it when removing a single version.
+                if version.is_some() {
+                    return Ok(summary);
+                }
+
                 // We can't know if the flat index includes a package, so we just remove the entire
                 // cache entry.
                 let root = cache.bucket(self);
@@ -1330,6 +1649,150 @@ impl CacheBucket {
         Ok(summary)
     }
 
+    /// Remove all entries from the cache bucket, except those for the given packages.
+    ///
+    /// Returns the number of entries removed from the cache.
+    fn remove_except(self, cache: &Cache, excluded: &[PackageName]) -> Result<Removal, io::Error> {
+        /// Returns `true` if the [`Path`] is a cache entry indexed by an excluded package name
+        /// (e.g., `torch` or `torch.rkyv`).
+        fn is_excluded(path: &Path, excluded: &[PackageName]) -> bool {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| excluded.iter().any(|name| name.as_str() == stem))
+        }
+
+        /// Returns `true` if the [`Path`] represents a built wheel for an excluded package.
+        fn is_excluded_build(path: &Path, excluded: &[PackageName]) -> bool {
+            let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
+                return false;
+            };
+            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
+                return false;
+            };
+            excluded.contains(&metadata.name)
+        }
+
+        /// Remove the cache entries in the given directory, indexed by name, except those for the
+        /// excluded packages.
+        fn remove_packages(directory: &Path, excluded: &[PackageName]) -> io::Result<Removal> {
+            let mut summary = Removal::default();
+            for entry in entries(directory)? {
+                if !is_excluded(&entry, excluded) {
+                    summary += rm_rf(entry)?;
+                }
+            }
+            Ok(summary)
+        }
+
+        let mut summary = Removal::default();
+        match self {
+            Self::Wheels => {
+                // For `pypi` wheels, we expect a directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                summary += remove_packages(&root, excluded)?;
+
+                // For alternate indices, direct URLs, and local paths, we expect a directory for
+                // every index, URL, or path, followed by a directory per package (indexed by name).
+                for kind in [
+                    WheelCacheKind::Index,
+                    WheelCacheKind::Url,
+                    WheelCacheKind::Path,
+                    WheelCacheKind::Editable,
+                ] {
+                    let root = cache.bucket(self).join(kind);
+                    for directory in directories(root)? {
+                        summary += remove_packages(&directory, excluded)?;
+                    }
+                }
+            }
+            Self::SourceDistributions => {
+                // For `pypi` source distributions, we expect a directory per package (indexed by
+                // name).
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                summary += remove_packages(&root, excluded)?;
+
+                // For alternate indices, we expect a directory for every index (under an `index`
+                // subdirectory), followed by a directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                for directory in directories(root)? {
+                    summary += remove_packages(&directory, excluded)?;
+                }
+
+                // For direct URLs and local paths, we expect a directory for every URL or path,
+                // followed by a directory per version. To determine whether the entry should be
+                // retained, we need to search for a wheel matching an excluded package name.
+                for kind in [
+                    WheelCacheKind::Url,
+                    WheelCacheKind::Path,
+                    WheelCacheKind::Editable,
+                ] {
+                    let root = cache.bucket(self).join(kind);
+                    for url in directories(root)? {
+                        if !directories(&url)?
+                            .any(|version_dir| is_excluded_build(&version_dir, excluded))
+                        {
+                            summary += rm_rf(url)?;
+                        }
+                    }
+                }
+
+                // For Git dependencies, we expect a directory for every repository, followed by a
+                // directory for every SHA.
+                let root = cache.bucket(self).join(WheelCacheKind::Git);
+                for repository in directories(root)? {
+                    for sha in directories(repository)? {
+                        if !is_excluded_build(&sha, excluded) {
+                            summary += rm_rf(sha)?;
+                        }
+                    }
+                }
+            }
+            Self::Simple => {
+                // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                summary += remove_packages(&root, excluded)?;
+
+                // For alternate indices, we expect a directory for every index (under an `index`
+                // subdirectory), followed by a rkyv file per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                for directory in directories(root)? {
+                    summary += remove_packages(&directory, excluded)?;
+                }
+            }
+            Self::Archive => {
+                // Archives are removed once they're no longer referenced by another bucket.
+            }
+            Self::FlatIndex
+            | Self::Git
+            | Self::Interpreter
+            | Self::Builds
+            | Self::Environments
+            | Self::Python
+            | Self::Binaries
+            | Self::Osv => {
+                // These entries can't be attributed to a package, so remove the entire bucket.
+                summary += rm_rf(cache.bucket(self))?;
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Returns `true` if the bucket contains build artifacts, source trees, or ephemeral
+    /// environments, as opposed to downloaded wheels, index metadata, or Python installations.
+    pub fn is_build_artifact(self) -> bool {
+        match self {
+            Self::SourceDistributions | Self::Git | Self::Builds | Self::Environments => true,
+            Self::Wheels
+            | Self::FlatIndex
+            | Self::Interpreter
+            | Self::Simple
+            | Self::Archive
+            | Self::Python
+            | Self::Binaries
+            | Self::Osv => false,
+        }
+    }
+
     /// Return an iterator over all cache buckets.
     fn iter() -> impl Iterator<Item = Self> {
         [
@@ -1460,6 +1923,166 @@ mod tests {
         assert!(Link::from_str("archive-v0/").is_err());
     }
 
+    #[test]
+    fn contains_wheel() {
+        use uv_distribution_filename::WheelFilename;
+
+        use super::{Cache, CacheBucket};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let wheels = cache_root.path().join(CacheBucket::Wheels.to_str());
+        let pypi = wheels.join("pypi").join("numpy");
+        let index = wheels.join("index").join("b2a7eb67d4c26b82").join("flask");
+        fs_err::create_dir_all(&pypi).unwrap();
+        fs_err::create_dir_all(&index).unwrap();
+        fs_err::write(pypi.join("1.26.4-cp312-cp312-linux_x86_64.http"), "").unwrap();
+        fs_err::write(index.join("3.0.0-py3-none-any.http"), "").unwrap();
+
+        let cache = Cache::from_path(cache_root.path());
+        assert!(cache.contains_wheel(
+            &WheelFilename::from_str("numpy-1.26.4-cp312-cp312-linux_x86_64.whl").unwrap()
+        ));
+        assert!(
+            cache.contains_wheel(&WheelFilename::from_str("flask-3.0.0-py3-none-any.whl").unwrap())
+        );
+        assert!(!cache.contains_wheel(
+            &WheelFilename::from_str("numpy-1.26.3-cp312-cp312-linux_x86_64.whl").unwrap()
+        ));
+        assert!(
+            !cache
+                .contains_wheel(&WheelFilename::from_str("flask-3.0.1-py3-none-any.whl").unwrap())
+        );
+    }
+
+    #[test]
+    fn age_of() {
+        use std::time::{Duration, SystemTime};
+
+        use uv_distribution_filename::WheelFilename;
+
+        use super::{Cache, CacheBucket};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let wheels = cache_root
+            .path()
+            .join(CacheBucket::Wheels.to_str())
+            .join("pypi")
+            .join("numpy");
+        fs_err::create_dir_all(&wheels).unwrap();
+        let pointer = wheels.join("1.26.4-cp312-cp312-linux_x86_64.http");
+        fs_err::write(&pointer, "").unwrap();
+
+        // Backdate the access time, such that the age is independent of the filesystem's
+        // `atime` behavior.
+        let accessed = SystemTime::now() - Duration::from_hours(1);
+        fs_err::File::options()
+            .write(true)
+            .open(&pointer)
+            .unwrap()
+            .set_times(std::fs::FileTimes::new().set_accessed(accessed))
+            .unwrap();
+
+        let cache = Cache::from_path(cache_root.path());
+        let age = cache
+            .age_of(&WheelFilename::from_str("numpy-1.26.4-cp312-cp312-linux_x86_64.whl").unwrap())
+            .unwrap();
+        assert!(age >= Duration::from_hours(1));
+
+        assert!(
+            cache
+                .age_of(
+                    &WheelFilename::from_str("numpy-1.26.3-cp312-cp312-linux_x86_64.whl").unwrap()
+                )
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn shared_lock_no_wait() {
+        use super::Cache;
+
+        let cache_root = tempfile::tempdir().unwrap();
+
+        // Multiple readers can hold the lock concurrently.
+        let reader = Cache::from_path(cache_root.path())
+            .with_shared_lock_no_wait()
+            .unwrap();
+        let other = Cache::from_path(cache_root.path())
+            .with_shared_lock_no_wait()
+            .unwrap();
+
+        // But a reader blocks an exclusive lock.
+        assert!(
+            Cache::from_path(cache_root.path())
+                .with_exclusive_lock_no_wait()
+                .is_err()
+        );
+        drop(reader);
+        drop(other);
+
+        // And an exclusive lock blocks readers.
+        let _writer = Cache::from_path(cache_root.path())
+            .with_exclusive_lock_no_wait()
+            .unwrap();
+        assert!(
+            Cache::from_path(cache_root.path())
+                .with_shared_lock_no_wait()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn remove_version() {
+        use uv_normalize::PackageName;
+        use uv_pep440::Version;
+
+        use super::{Cache, CacheBucket};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let wheels = cache_root
+            .path()
+            .join(CacheBucket::Wheels.to_str())
+            .join("pypi")
+            .join("numpy");
+        let source_distributions = cache_root
+            .path()
+            .join(CacheBucket::SourceDistributions.to_str())
+            .join("pypi")
+            .join("numpy");
+        let simple = cache_root
+            .path()
+            .join(CacheBucket::Simple.to_str())
+            .join("pypi")
+            .join("numpy.rkyv");
+
+        fs_err::create_dir_all(&wheels).unwrap();
+        fs_err::write(wheels.join("1.26.3-cp312-cp312-linux_x86_64.http"), "").unwrap();
+        fs_err::write(wheels.join("1.26.4-cp312-cp312-linux_x86_64.http"), "").unwrap();
+        fs_err::create_dir_all(source_distributions.join("1.26.3")).unwrap();
+        fs_err::create_dir_all(source_distributions.join("1.26.4")).unwrap();
+        fs_err::create_dir_all(simple.parent().unwrap()).unwrap();
+        fs_err::write(&simple, "").unwrap();
+
+        let summary = Cache::from_path(cache_root.path())
+            .remove_version(
+                &PackageName::from_str("numpy").unwrap(),
+                &Version::from_str("1.26.4").unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(summary.num_files, 1);
+        assert_eq!(summary.num_dirs, 1);
+        assert!(
+            wheels
+                .join("1.26.3-cp312-cp312-linux_x86_64.http")
+                .is_file()
+        );
+        assert!(!wheels.join("1.26.4-cp312-cp312-linux_x86_64.http").exists());
+        assert!(source_distributions.join("1.26.3").is_dir());
+        assert!(!source_distributions.join("1.26.4").exists());
+        assert!(simple.is_file());
+    }
+
     #[test]
     #[cfg(unix)]
     fn prune_does_not_follow_environment_symlinks() {
@@ -1537,4 +2160,257 @@ mod tests {
         assert!(victim_dir.join("payload.txt").is_file());
         assert!(fs_err::symlink_metadata(symlink).is_err());
     }
+
+    #[test]
+    fn remove_build_artifacts() {
+        use super::{Cache, CacheBucket};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let wheels = cache_root
+            .path()
+            .join(CacheBucket::Wheels.to_str())
+            .join("pypi")
+            .join("numpy");
+        let source_distributions = cache_root
+            .path()
+            .join(CacheBucket::SourceDistributions.to_str());
+        let builds = cache_root.path().join(CacheBucket::Builds.to_str());
+
+        fs_err::create_dir_all(&wheels).unwrap();
+        fs_err::write(wheels.join("1.26.4-cp312-cp312-linux_x86_64.http"), "").unwrap();
+        fs_err::create_dir_all(source_distributions.join("pypi").join("numpy")).unwrap();
+        fs_err::create_dir_all(builds.join(".tmpabc")).unwrap();
+
+        let (buckets, summary) = Cache::from_path(cache_root.path())
+            .remove_build_artifacts()
+            .unwrap();
+
+        assert_eq!(
+            buckets,
+            vec![CacheBucket::SourceDistributions, CacheBucket::Builds]
+        );
+        assert_eq!(summary.num_files, 0);
+        assert_eq!(summary.num_dirs, 5);
+        assert!(
+            wheels
+                .join("1.26.4-cp312-cp312-linux_x86_64.http")
+                .is_file()
+        );
+        assert!(!source_distributions.exists());
+        assert!(!builds.exists());
+    }
+
+    #[test]
+    fn clear_parallel() {
+        use std::num::NonZeroUsize;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use super::{Cache, CacheBucket, CleanReporter};
+
+        struct CountingReporter {
+            cleaned: Arc<AtomicUsize>,
+            completed: Arc<AtomicUsize>,
+        }
+
+        impl CleanReporter for CountingReporter {
+            fn on_clean(&self) {
+                self.cleaned.fetch_add(1, Ordering::Relaxed);
+            }
+
+            fn on_complete(&self) {
+                self.completed.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        for concurrency in [1, 4] {
+            let cache_root = tempfile::tempdir().unwrap();
+            let root = cache_root.path().join("cache");
+            for bucket in [CacheBucket::Wheels, CacheBucket::Archive] {
+                for entry in 0..8 {
+                    let directory = root.join(bucket.to_str()).join(format!("entry-{entry}"));
+                    fs_err::create_dir_all(&directory).unwrap();
+                    for file in 0..4 {
+                        fs_err::write(directory.join(format!("file-{file}")), "data").unwrap();
+                    }
+                }
+            }
+            fs_err::write(root.join("CACHEDIR.TAG"), "").unwrap();
+
+            let cleaned = Arc::new(AtomicUsize::new(0));
+            let completed = Arc::new(AtomicUsize::new(0));
+            let reporter = CountingReporter {
+                cleaned: cleaned.clone(),
+                completed: completed.clone(),
+            };
+
+            let summary = Cache::from_path(&root)
+                .clear(Box::new(reporter), NonZeroUsize::new(concurrency).unwrap())
+                .unwrap();
+
+            // Each of the 64 files and the `CACHEDIR.TAG`, plus the `.lock` counted on removal.
+            assert_eq!(summary.num_files, 66);
+            // Each of the 16 entries, the 2 buckets, and the root.
+            assert_eq!(summary.num_dirs, 19);
+            assert_eq!(summary.total_bytes, 64 * 4);
+            assert_eq!(cleaned.load(Ordering::Relaxed), 64 + 1 + 16 + 2);
+            assert_eq!(completed.load(Ordering::Relaxed), 1);
+            assert!(!root.exists());
+        }
+    }
+
+    #[test]
+    fn remove_packages_parallel() {
+        use std::num::NonZeroUsize;
+        use std::sync::Mutex;
+
+        use uv_normalize::PackageName;
+        use uv_pep440::Version;
+
+        use super::{Cache, CacheBucket, Removal};
+
+        /// Populate a cache with wheels, index metadata, and flat index entries for a set of
+        /// packages.
+        fn populate(root: &std::path::Path) {
+            for package in ["anyio", "idna", "numpy", "sniffio", "flask", "iniconfig"] {
+                let wheels = root
+                    .join(CacheBucket::Wheels.to_str())
+                    .join("pypi")
+                    .join(package);
+                fs_err::create_dir_all(&wheels).unwrap();
+                for version in ["1.0.0", "2.0.0"] {
+                    fs_err::write(wheels.join(format!("{version}-py3-none-any.http")), package)
+                        .unwrap();
+                }
+                let simple = root.join(CacheBucket::Simple.to_str()).join("pypi");
+                fs_err::create_dir_all(&simple).unwrap();
+                fs_err::write(simple.join(format!("{package}.rkyv")), package).unwrap();
+            }
+            let flat_index = root.join(CacheBucket::FlatIndex.to_str());
+            fs_err::create_dir_all(&flat_index).unwrap();
+            fs_err::write(flat_index.join("index.msgpack"), "flat").unwrap();
+        }
+
+        let packages = [
+            ("anyio", None),
+            ("idna", Some("1.0.0")),
+            ("numpy", None),
+            ("numpy", Some("2.0.0")),
+            ("sniffio", None),
+            ("flask", Some("2.0.0")),
+            ("missing", None),
+        ]
+        .map(|(name, version)| {
+            (
+                PackageName::from_str(name).unwrap(),
+                version.map(|version| Version::from_str(version).unwrap()),
+            )
+        });
+
+        // Remove each package in turn.
+        let serial_root = tempfile::tempdir().unwrap();
+        populate(serial_root.path());
+        let cache = Cache::from_path(serial_root.path());
+        let mut expected = Removal::default();
+        for (name, version) in &packages {
+            expected += match version {
+                Some(version) => cache.remove_version(name, version).unwrap(),
+                None => cache.remove(name).unwrap(),
+            };
+        }
+
+        // Remove the packages concurrently.
+        let parallel_root = tempfile::tempdir().unwrap();
+        populate(parallel_root.path());
+        let removed = Mutex::new(Vec::new());
+        let summary = Cache::from_path(parallel_root.path())
+            .remove_packages(&packages, NonZeroUsize::new(4).unwrap(), |index, _| {
+                removed.lock().unwrap().push(index);
+            })
+            .unwrap();
+
+        assert_eq!(summary.num_files, expected.num_files);
+        assert_eq!(summary.num_dirs, expected.num_dirs);
+        assert_eq!(summary.total_bytes, expected.total_bytes);
+
+        // The callback is invoked exactly once per package.
+        let mut removed = removed.into_inner().unwrap();
+        removed.sort_unstable();
+        assert_eq!(removed, (0..packages.len()).collect::<Vec<_>>());
+
+        // The same entries remain.
+        for root in [serial_root.path(), parallel_root.path()] {
+            let wheels = root.join(CacheBucket::Wheels.to_str()).join("pypi");
+            assert!(!wheels.join("anyio").exists());
+            assert!(!wheels.join("idna").join("1.0.0-py3-none-any.http").exists());
+            assert!(
+                wheels
+                    .join("idna")
+                    .join("2.0.0-py3-none-any.http")
+                    .is_file()
+            );
+            assert!(!wheels.join("numpy").exists());
+            assert!(
+                wheels
+                    .join("flask")
+                    .join("1.0.0-py3-none-any.http")
+                    .is_file()
+            );
+            assert!(wheels.join("iniconfig").is_dir());
+            assert!(!root.join(CacheBucket::FlatIndex.to_str()).exists());
+        }
+    }
+
+    #[test]
+    fn clear_except() {
+        use uv_normalize::PackageName;
+
+        use super::{Cache, CacheBucket};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let wheels = cache_root
+            .path()
+            .join(CacheBucket::Wheels.to_str())
+            .join("pypi");
+        let simple = cache_root
+            .path()
+            .join(CacheBucket::Simple.to_str())
+            .join("pypi");
+        let interpreter = cache_root.path().join(CacheBucket::Interpreter.to_str());
+
+        for package in ["numpy", "torch"] {
+            fs_err::create_dir_all(wheels.join(package)).unwrap();
+            fs_err::write(
+                wheels
+                    .join(package)
+                    .join("1.0.0-cp312-cp312-linux_x86_64.http"),
+                "",
+            )
+            .unwrap();
+        }
+        fs_err::create_dir_all(&simple).unwrap();
+        fs_err::write(simple.join("numpy.rkyv"), "").unwrap();
+        fs_err::write(simple.join("torch.rkyv"), "").unwrap();
+        fs_err::write(simple.join("torch.lock"), "").unwrap();
+        fs_err::create_dir_all(&interpreter).unwrap();
+        fs_err::write(interpreter.join("abc.msgpack"), "").unwrap();
+
+        let summary = Cache::from_path(cache_root.path())
+            .clear_except(&[PackageName::from_str("torch").unwrap()])
+            .unwrap();
+
+        assert_eq!(summary.num_files, 3);
+        assert_eq!(summary.num_dirs, 2);
+        assert!(!wheels.join("numpy").exists());
+        assert!(
+            wheels
+                .join("torch")
+                .join("1.0.0-cp312-cp312-linux_x86_64.http")
+                .is_file()
+        );
+        assert!(!simple.join("numpy.rkyv").exists());
+        assert!(simple.join("torch.rkyv").is_file());
+        assert!(simple.join("torch.lock").is_file());
+        assert!(!interpreter.exists());
+    }
 }
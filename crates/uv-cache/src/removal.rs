@@ -3,7 +3,9 @@
 //! Source: <https://github.com/rust-lang/cargo/blob/e1ebce1035f9b53bb46a55bd4b0ecf51e24c6458/src/cargo/ops/cargo_clean.rs#L324>
 
 use std::io;
+use std::num::NonZeroUsize;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::CleanReporter;
 
@@ -36,6 +38,64 @@ impl Remover {
     ) -> io::Result<Removal> {
         let mut removal = Removal::default();
         removal.rm_rf(path.as_ref(), self.reporter.as_deref(), skip_locked_file)?;
+        if let Some(reporter) = self.reporter.as_deref() {
+            reporter.on_complete();
+        }
+        Ok(removal)
+    }
+
+    /// Like [`Remover::rm_rf`], but removes the entries two levels below `path` (e.g., the entries
+    /// in each cache bucket) across the given number of threads, before removing the remainder.
+    ///
+    /// With a concurrency of one, this is equivalent to [`Remover::rm_rf`].
+    pub(crate) fn rm_rf_parallel(
+        &self,
+        path: impl AsRef<Path>,
+        skip_locked_file: bool,
+        concurrency: NonZeroUsize,
+    ) -> io::Result<Removal> {
+        let path = path.as_ref();
+        if concurrency.get() == 1 {
+            return self.rm_rf(path, skip_locked_file);
+        }
+
+        // Collect the entries in each subdirectory, without following symlinks.
+        let mut targets = Vec::new();
+        for entry in uv_fs::entries(path)? {
+            if fs_err::symlink_metadata(&entry).is_ok_and(|metadata| metadata.is_dir()) {
+                targets.extend(uv_fs::entries(&entry)?);
+            }
+        }
+
+        let reporter = self.reporter.as_deref();
+        let next = AtomicUsize::new(0);
+        let workers = concurrency.get().min(targets.len());
+        let results = std::thread::scope(|scope| {
+            let handles = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut removal = Removal::default();
+                        while let Some(target) = targets.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            removal.rm_rf(target, reporter, false)?;
+                        }
+                        Ok::<_, io::Error>(removal)
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("cache removal thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        let mut removal = Removal::default();
+        for result in results {
+            removal += result?;
+        }
+
+        // Remove whatever remains, i.e., the (now empty) subdirectories and any top-level files.
+        removal += self.rm_rf(path, skip_locked_file)?;
+
         Ok(removal)
     }
 }
@@ -167,8 +227,6 @@ impl Removal {
             reporter.map(CleanReporter::on_clean);
         }
 
-        reporter.map(CleanReporter::on_complete);
-
         Ok(())
     }
 }
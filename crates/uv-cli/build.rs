@@ -18,6 +18,7 @@ fn main() {
         .to_path_buf();
 
     commit_info(&workspace_root);
+    rustc_version();
 
     let target = std::env::var(EnvVars::TARGET).unwrap();
     println!("cargo:rustc-env=RUST_HOST_TARGET={target}");
@@ -96,6 +97,19 @@ fn commit_info(workspace_root: &Path) {
     }
 }
 
+fn rustc_version() {
+    let rustc = std::env::var(EnvVars::RUSTC).unwrap_or_else(|_| "rustc".to_string());
+    let output = match Command::new(rustc).arg("--version").output() {
+        Ok(output) if output.status.success() => output,
+        _ => return,
+    };
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // The output is in the format "rustc <version> (<commit> <date>)"
+    let version = stdout.trim();
+    let version = version.strip_prefix("rustc ").unwrap_or(version);
+    println!("cargo:rustc-env={}={version}", EnvVars::UV_RUSTC_VERSION);
+}
+
 fn git_head(git_dir: &Path) -> Option<PathBuf> {
     // The typical case is a standard git repository.
     let git_head_path = git_dir.join("HEAD");
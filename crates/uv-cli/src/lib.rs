@@ -15,14 +15,16 @@ use uv_audit::VulnerabilityServiceFormat;
 use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ExportFormat, IndexStrategy, KeyringProviderType, NoSourcesPackage, PackageNameSpecifier,
+    PipCompileFormat, ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
     PipFindLinks, PipIndex,
 };
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
+use uv_pep440::Version;
 use uv_pep508::{MarkerTree, Requirement};
 use uv_preview::MaybePreviewFeature;
 use uv_pypi_types::VerbatimParsedUrl;
@@ -59,6 +61,15 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonFindFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -861,6 +872,11 @@ pub struct SelfNamespace {
 pub enum SelfCommand {
     /// Update uv.
     Update(SelfUpdateArgs),
+    /// Uninstall uv.
+    ///
+    /// Removes the `uv` and `uvx` executables, along with any managed Python installations and
+    /// installed tools. The cache is only removed if `--remove-cache` is provided.
+    Uninstall(SelfUninstallArgs),
     /// Display uv's version
     Version {
         /// Only print the version
@@ -885,6 +901,23 @@ pub struct SelfUpdateArgs {
     /// Run without performing the update.
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Check whether a newer version of uv is available, without downloading it.
+    ///
+    /// The result of the check is cached for 24 hours to avoid rate-limiting.
+    #[arg(long, conflicts_with_all = ["target_version", "dry_run"])]
+    pub check: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct SelfUninstallArgs {
+    /// Also remove the cache directory.
+    #[arg(long)]
+    pub remove_cache: bool,
+
+    /// Proceed without prompting for confirmation before each removal.
+    #[arg(long, short)]
+    pub yes: bool,
 }
 
 #[derive(Args)]
@@ -925,8 +958,45 @@ pub enum CacheCommand {
 #[derive(Args, Debug)]
 pub struct CleanArgs {
     /// The packages to remove from the cache.
+    ///
+    /// A single version of a package can be removed with `<package>==<version>`, e.g.,
+    /// `numpy==1.26.4`. Otherwise, all versions of the package are removed.
     #[arg(value_hint = ValueHint::Other)]
-    pub package: Vec<PackageName>,
+    pub package: Vec<CleanPackage>,
+
+    /// Remove the packages named in the given `requirements.txt` files from the cache.
+    ///
+    /// All versions of each named package are removed, regardless of any version specifiers.
+    ///
+    /// If `-` is provided, then the packages will be read from stdin, e.g., to remove the packages
+    /// whose sources changed since a given Git reference.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub packages_from: Vec<PathBuf>,
+
+    /// Remove only build artifacts from the cache, retaining downloaded wheels.
+    ///
+    /// Removes source distributions and the wheels built from them, Git checkouts, and ephemeral
+    /// environments, while preserving downloaded wheels and index metadata for offline use.
+    #[arg(long, conflicts_with_all = ["package", "packages_from"])]
+    pub keep_wheels: bool,
+
+    /// Clear the entire cache, except for the entries for the given packages.
+    ///
+    /// Entries that can't be attributed to a package, such as Git checkouts and cached
+    /// environments, are removed.
+    #[arg(
+        long,
+        value_hint = ValueHint::Other,
+        conflicts_with_all = ["package", "packages_from", "keep_wheels"]
+    )]
+    pub exclude: Vec<PackageName>,
+
+    /// Report the total number of inodes (files and directories) freed.
+    ///
+    /// Useful on filesystems where the number of files, rather than their size, is the limiting
+    /// factor.
+    #[arg(long)]
+    pub show_inodes: bool,
 
     /// Force removal of the cache, ignoring in-use checks.
     ///
@@ -936,6 +1006,40 @@ pub struct CleanArgs {
     pub force: bool,
 }
 
+/// A package to remove from the cache, optionally restricted to a single version (e.g.,
+/// `numpy==1.26.4`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanPackage {
+    pub name: PackageName,
+    pub version: Option<Version>,
+}
+
+impl FromStr for CleanPackage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, version) = match s.split_once("==") {
+            Some((name, version)) => (name.trim(), Some(version.trim())),
+            None => (s, None),
+        };
+        let name = PackageName::from_str(name).map_err(|err| err.to_string())?;
+        let version = version
+            .map(Version::from_str)
+            .transpose()
+            .map_err(|err| err.to_string())?;
+        Ok(Self { name, version })
+    }
+}
+
+impl Display for CleanPackage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{}=={version}", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct PruneArgs {
     /// Optimize the cache for persistence in a continuous integration environment, like GitHub
@@ -1387,6 +1491,14 @@ fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     }
 }
 
+/// Parse a Python installation mirror, validating that it is a URL.
+fn parse_mirror_url(input: &str) -> Result<String, String> {
+    match DisplaySafeUrl::parse(input) {
+        Ok(_) => Ok(input.to_string()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
 // Parse a string, mapping the empty string to `None`.
 #[expect(clippy::unnecessary_wraps)]
 fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
@@ -2975,9 +3087,29 @@ pub struct BuildArgs {
     ///
     /// Defaults to the `dist` subdirectory within the source directory, or the
     /// directory containing the source distribution archive.
-    #[arg(long, short, value_parser = parse_file_path, value_hint = ValueHint::DirPath)]
+    ///
+    /// Also available as `--output-dir`.
+    #[arg(
+        long,
+        short,
+        alias = "output-dir",
+        value_parser = parse_file_path,
+        value_hint = ValueHint::DirPath
+    )]
     pub out_dir: Option<PathBuf>,
 
+    /// The output directory to which source distributions should be written.
+    ///
+    /// Takes precedence over `--out-dir` for source distributions.
+    #[arg(long, value_parser = parse_file_path, value_hint = ValueHint::DirPath)]
+    pub sdist_dir: Option<PathBuf>,
+
+    /// The output directory to which wheels should be written.
+    ///
+    /// Takes precedence over `--out-dir` for wheels.
+    #[arg(long, value_parser = parse_file_path, value_hint = ValueHint::DirPath)]
+    pub wheel_dir: Option<PathBuf>,
+
     /// Build a source distribution ("sdist") from the given directory.
     #[arg(long)]
     pub sdist: bool,
@@ -2998,6 +3130,14 @@ pub struct BuildArgs {
     #[arg(long, hide = true)]
     pub list: bool,
 
+    /// Only prepare the wheel metadata, without building the wheel.
+    ///
+    /// Calls the build backend's `prepare_metadata_for_build_wheel` hook and writes the resulting
+    /// `.dist-info` directory to the output directory. This is faster than a full build for
+    /// packages with expensive build steps, such as compiled extensions.
+    #[arg(long, conflicts_with_all = ["sdist", "list"])]
+    pub metadata_only: bool,
+
     #[arg(long, overrides_with("no_build_logs"), hide = true)]
     pub build_logs: bool,
 
@@ -3171,6 +3311,25 @@ pub struct VenvArgs {
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new())]
     pub seed: bool,
 
+    /// Ensure that no seed packages (`pip`, `setuptools`, or `wheel`) are present in the virtual
+    /// environment.
+    ///
+    /// uv omits seed packages by default; this flag makes the absence explicit. It overrides
+    /// `UV_VENV_SEED`, and `uv venv` will exit with an error if any seed packages are found in the
+    /// resulting environment, e.g., when used with `--allow-existing`.
+    #[arg(long, conflicts_with = "seed")]
+    pub without_pip: bool,
+
+    /// Upgrade the seed packages (`pip`, `setuptools`, and `wheel`) to the latest available
+    /// versions.
+    ///
+    /// By default, cached index responses may be used when resolving the seed packages. With
+    /// `--upgrade-deps`, the latest versions are fetched from the index instead.
+    ///
+    /// Only has an effect when combined with `--seed`.
+    #[arg(long, conflicts_with = "without_pip")]
+    pub upgrade_deps: bool,
+
     /// Remove any existing files or directories at the target path [env: UV_VENV_CLEAR=]
     ///
     /// By default, `uv venv` will exit with an error if the given path is non-empty. The
@@ -3234,6 +3393,10 @@ pub struct VenvArgs {
     /// pip install`. The `--system-site-packages` flag will provide the virtual environment with
     /// access to the system site packages directory at runtime, but will not affect the behavior of
     /// uv commands.
+    ///
+    /// As a result, packages installed into the virtual environment may conflict with the system
+    /// packages that are visible at runtime, e.g., if they require different versions of a shared
+    /// dependency.
     #[arg(long)]
     pub system_site_packages: bool,
 
@@ -3242,6 +3405,10 @@ pub struct VenvArgs {
     /// A relocatable virtual environment can be moved around and redistributed without invalidating
     /// its associated entrypoint and activation scripts.
     ///
+    /// This is useful when the environment is created at a different path than the one it will be
+    /// used from, e.g., when building a Docker image in which the environment is copied to a
+    /// different location in the final stage.
+    ///
     /// Note that this can only be guaranteed for standard `console_scripts` and `gui_scripts`.
     /// Other scripts may be adjusted if they ship with a generic `#!python[w]` shebang, and
     /// binaries are left as-is.
@@ -3329,7 +3496,7 @@ pub struct VenvArgs {
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
     /// will break all installed packages by way of removing the underlying source files. Use
     /// symlinks with caution.
-    #[arg(long, value_enum, env = EnvVars::UV_LINK_MODE)]
+    #[arg(long, env = EnvVars::UV_LINK_MODE)]
     pub link_mode: Option<uv_install_wheel::LinkMode>,
 
     #[command(flatten)]
@@ -3383,6 +3550,61 @@ pub enum AuthorFrom {
     None,
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReadmeFormat {
+    /// Create a Markdown `README.md` file.
+    #[default]
+    Md,
+    /// Create a reStructuredText `README.rst` file.
+    Rst,
+    /// Create a plain text `README.txt` file.
+    Txt,
+}
+
+impl ReadmeFormat {
+    /// Returns the file name of the README for this format.
+    pub fn filename(self) -> &'static str {
+        match self {
+            Self::Md => "README.md",
+            Self::Rst => "README.rst",
+            Self::Txt => "README.txt",
+        }
+    }
+}
+
+/// A template from which to initialize a project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitTemplate {
+    /// The built-in library template, equivalent to `--lib`.
+    Library,
+    /// The built-in application template, equivalent to `--app`.
+    Application,
+    /// The built-in script template, equivalent to `--script`.
+    Script,
+    /// The built-in extension module template, equivalent to `--lib --build-backend maturin`.
+    ExtensionModule,
+    /// A local directory containing a `uv-template.toml` manifest.
+    Directory(PathBuf),
+}
+
+impl FromStr for InitTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "library" => Ok(Self::Library),
+            "application" => Ok(Self::Application),
+            "script" => Ok(Self::Script),
+            "extension-module" => Ok(Self::ExtensionModule),
+            _ if s.starts_with("http://") || s.starts_with("https://") => Err(
+                "Templates can't be fetched from a URL; provide a path to a local template directory"
+                    .to_string(),
+            ),
+            _ => Ok(Self::Directory(PathBuf::from(s))),
+        }
+    }
+}
+
 #[derive(Args)]
 pub struct InitArgs {
     /// The path to use for the project/script.
@@ -3470,6 +3692,18 @@ pub struct InitArgs {
     #[arg(long, conflicts_with_all=["app", "lib", "package", "build_backend", "description"])]
     pub r#script: bool,
 
+    /// Initialize the project from a template.
+    ///
+    /// Accepts one of the built-in templates (`library`, `application`, `script`, or
+    /// `extension-module`), or a path to a template directory.
+    ///
+    /// A template directory contains a `uv-template.toml` manifest alongside the files to create.
+    /// Placeholders of the form `{{ name }}` in file contents and paths are replaced with the
+    /// project `name`, `module` name, `description`, and `requires_python`, or with the defaults
+    /// from the `[variables]` table of the manifest. Other placeholders are left untouched.
+    #[arg(long, conflicts_with_all = ["app", "lib", "script", "bare"], value_hint = ValueHint::DirPath)]
+    pub template: Option<InitTemplate>,
+
     /// Set the project description.
     #[arg(long, conflicts_with = "script", overrides_with = "no_description", value_hint = ValueHint::Other)]
     pub description: Option<String>,
@@ -3505,6 +3739,26 @@ pub struct InitArgs {
     #[arg(long)]
     pub no_readme: bool,
 
+    /// The format of the README file to create.
+    ///
+    /// The `readme` field in the `pyproject.toml` is set to the created file. Defaults to
+    /// Markdown (`README.md`).
+    #[arg(long, value_enum, conflicts_with = "no_readme")]
+    pub readme_format: Option<ReadmeFormat>,
+
+    /// Create a `LICENSE` file with the text of the given license.
+    ///
+    /// Accepts the SPDX identifier of a common license, e.g., `MIT` or `Apache-2.0`. The copyright
+    /// notice is filled in with the current year and the project author, if known.
+    #[arg(long, conflicts_with = "script", value_hint = ValueHint::Other)]
+    pub license: Option<String>,
+
+    /// Mark the project as private, to prevent accidental uploads to PyPI.
+    ///
+    /// Adds the `Private :: Do Not Upload` classifier to the `pyproject.toml`, which PyPI rejects.
+    #[arg(long, conflicts_with = "script")]
+    pub private: bool,
+
     /// Fill in the `authors` field in the `pyproject.toml`.
     ///
     /// By default, uv will attempt to infer the author information from some sources (e.g., Git)
@@ -4645,9 +4899,22 @@ pub struct AddArgs {
 #[derive(Args)]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true, value_hint = ValueHint::Other)]
+    #[arg(required_unless_present = "all", conflicts_with = "all", value_hint = ValueHint::Other)]
     pub packages: Vec<Requirement<VerbatimParsedUrl>>,
 
+    /// Remove all dependencies from the project.
+    ///
+    /// By default, all packages in `project.dependencies` are removed. Use `--optional`,
+    /// `--group`, or `--dev` to remove all packages from another dependency table instead.
+    #[arg(long)]
+    pub all: bool,
+
+    /// Exclude the specified package from removal when using `--all`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, requires = "all", value_hint = ValueHint::Other)]
+    pub keep: Vec<PackageName>,
+
     /// Remove the packages from the development dependency group [env: UV_DEV=]
     ///
     /// This option is an alias for `--group dev`.
@@ -4760,6 +5027,19 @@ pub struct TreeArgs {
     #[arg(long, value_enum, default_value_t = TreeFormat::default())]
     pub format: TreeFormat,
 
+    /// Show the license of each package in the tree.
+    ///
+    /// Licenses are read from the metadata of the packages installed in the project environment.
+    /// Packages that are not installed are displayed without a license.
+    #[arg(long)]
+    pub licenses: bool,
+
+    /// Display only packages with the given licenses (e.g., `MIT,Apache-2.0`).
+    ///
+    /// Licenses are compared case-insensitively. Implies `--licenses`.
+    #[arg(long, value_delimiter = ',', value_hint = ValueHint::Other)]
+    pub licenses_filter: Vec<String>,
+
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
@@ -5006,6 +5286,13 @@ pub struct ExportArgs {
     #[arg(long, overrides_with("no_annotate"), hide = true)]
     pub annotate: bool,
 
+    /// The style of the annotation comments included in the output file, used to indicate the
+    /// source of each package.
+    ///
+    /// Only applies to the `requirements.txt` format. Defaults to `split`.
+    #[arg(long, value_enum)]
+    pub annotation_style: Option<AnnotationStyle>,
+
     /// Exclude the comment header at the top of the generated output file.
     #[arg(long, overrides_with("header"))]
     pub no_header: bool,
@@ -6456,7 +6743,6 @@ pub struct ToolUpgradeArgs {
     /// symlinks with caution.
     #[arg(
         long,
-        value_enum,
         env = EnvVars::UV_LINK_MODE,
         help_heading = "Installer options"
     )]
@@ -6504,8 +6790,11 @@ pub struct ToolUpgradeArgs {
     pub no_sources: bool,
 
     /// Don't use sources from the `tool.uv.sources` table for the specified packages [env: `UV_NO_SOURCES_PACKAGE`=]
+    ///
+    /// A package may be followed by a list of extras (e.g., `flask[async]`) to only ignore its
+    /// sources when all of those extras are requested.
     #[arg(long, help_heading = "Resolver options", value_delimiter = ' ')]
-    pub no_sources_package: Vec<PackageName>,
+    pub no_sources_package: Vec<NoSourcesPackage>,
 
     #[command(flatten)]
     pub build: BuildOptionsArgs,
@@ -6603,6 +6892,32 @@ pub enum PythonCommand {
     /// The Python executable directory may be overridden with `$UV_PYTHON_BIN_DIR`.
     Dir(PythonDirArgs),
 
+    /// Show the expected SHA-256 hash of a Python download.
+    ///
+    /// The hash can be used to verify a Python distribution out-of-band, e.g., before installing it
+    /// with `uv python install`.
+    ///
+    /// By default, the hash of the download for the current platform is shown. Use `--platform` to
+    /// show the hash for another platform.
+    ///
+    /// See `uv help python` to view supported request formats.
+    Hash(PythonHashArgs),
+
+    /// Run a command with a specific Python version.
+    ///
+    /// The requested Python version is discovered or, if necessary, downloaded, and its executable
+    /// directory is prepended to the `PATH` before running the command. For example,
+    /// `uv python exec 3.12 -- python -c "import sys; print(sys.version)"`.
+    ///
+    /// Unlike `uvx python@3.12`, any command that expects a specific Python on the `PATH` can be
+    /// invoked.
+    ///
+    /// Virtual environments are ignored; only system and managed Python installations are
+    /// considered.
+    ///
+    /// See `uv help python` to view supported request formats.
+    Exec(PythonExecArgs),
+
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
 
@@ -6648,15 +6963,22 @@ pub struct PythonListArgs {
     /// Only show installed Python versions.
     ///
     /// By default, installed distributions and available downloads for the current platform are shown.
-    #[arg(long, conflicts_with("only_downloads"))]
+    #[arg(long, conflicts_with_all = ["only_downloads", "only_managed"])]
     pub only_installed: bool,
 
     /// Only show available Python downloads.
     ///
     /// By default, installed distributions and available downloads for the current platform are shown.
-    #[arg(long, conflicts_with("only_installed"))]
+    #[arg(long, conflicts_with_all = ["only_installed", "only_managed"])]
     pub only_downloads: bool,
 
+    /// Only show Python versions installed in the managed Python directory.
+    ///
+    /// Unlike `--only-installed`, Python interpreters discovered elsewhere on the system, e.g., on
+    /// the `PATH`, are not shown.
+    #[arg(long, conflicts_with_all = ["only_installed", "only_downloads"])]
+    pub only_managed: bool,
+
     /// Show the URLs of available Python downloads.
     ///
     /// By default, these display as `<download available>`.
@@ -6687,6 +7009,40 @@ pub struct PythonDirArgs {
     pub bin: bool,
 }
 
+#[derive(Args)]
+pub struct PythonHashArgs {
+    /// The Python version request.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub request: String,
+
+    /// The platform of the Python download, e.g., `linux-x86_64-gnu` or `macos-aarch64-none`.
+    ///
+    /// Defaults to the current platform.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub platform: Option<String>,
+
+    /// URL pointing to JSON of custom Python installations.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub python_downloads_json_url: Option<String>,
+}
+
+#[derive(Args)]
+pub struct PythonExecArgs {
+    /// The Python version request.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub request: String,
+
+    /// The command to run, e.g., `python -c "import sys; print(sys.version)"`.
+    #[arg(last = true, required = true, value_hint = ValueHint::CommandWithArguments)]
+    pub command: Vec<OsString>,
+
+    /// URL pointing to JSON of custom Python installations.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub python_downloads_json_url: Option<String>,
+}
+
 #[derive(Args)]
 pub struct PythonInstallCompileBytecodeArgs {
     /// Compile Python's standard library to bytecode after installation.
@@ -6779,7 +7135,7 @@ pub struct PythonInstallArgs {
     /// `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
     ///
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
-    #[arg(long, value_hint = ValueHint::Url)]
+    #[arg(long, alias = "mirror-url", value_parser = parse_mirror_url, value_hint = ValueHint::Url)]
     pub mirror: Option<String>,
 
     /// Set the URL to use as the source for downloading PyPy installations.
@@ -6877,7 +7233,7 @@ pub struct PythonUpgradeArgs {
     /// `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
     ///
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
-    #[arg(long, value_hint = ValueHint::Url)]
+    #[arg(long, alias = "mirror-url", value_parser = parse_mirror_url, value_hint = ValueHint::Url)]
     pub mirror: Option<String>,
 
     /// Set the URL to use as the source for downloading PyPy installations.
@@ -6991,6 +7347,13 @@ pub struct PythonFindArgs {
     #[arg(long)]
     pub resolve_links: bool,
 
+    /// Select the output format.
+    ///
+    /// The JSON format includes the path, version, implementation, and architecture of the Python
+    /// interpreter, and whether it is managed by uv.
+    #[arg(long, value_enum, default_value_t = PythonFindFormat::default(), conflicts_with = "show_version")]
+    pub output_format: PythonFindFormat,
+
     /// URL pointing to JSON of custom Python installations.
     #[arg(long, value_hint = ValueHint::Other)]
     pub python_downloads_json_url: Option<String>,
@@ -7045,7 +7408,14 @@ pub struct PythonPinArgs {
     pub global: bool,
 
     /// Remove the Python version pin.
-    #[arg(long, conflicts_with = "request", conflicts_with = "resolved")]
+    ///
+    /// If no Python version pin exists, no changes are made.
+    #[arg(
+        long,
+        alias = "remove",
+        conflicts_with = "request",
+        conflicts_with = "resolved"
+    )]
     pub rm: bool,
 
     /// URL pointing to JSON of custom Python installations.
@@ -7526,7 +7896,6 @@ pub struct InstallerArgs {
     /// symlinks with caution.
     #[arg(
         long,
-        value_enum,
         env = EnvVars::UV_LINK_MODE,
         help_heading = "Installer options"
     )]
@@ -7574,8 +7943,11 @@ pub struct InstallerArgs {
     no_sources: bool,
 
     /// Don't use sources from the `tool.uv.sources` table for the specified packages [env: `UV_NO_SOURCES_PACKAGE`=]
+    ///
+    /// A package may be followed by a list of extras (e.g., `flask[async]`) to only ignore its
+    /// sources when all of those extras are requested.
     #[arg(long, help_heading = "Resolver options", value_delimiter = ' ')]
-    no_sources_package: Vec<PackageName>,
+    no_sources_package: Vec<NoSourcesPackage>,
 }
 
 /// Arguments that are used by commands that need to resolve (but not install) packages.
@@ -7778,7 +8150,6 @@ pub struct ResolverArgs {
     /// symlinks with caution.
     #[arg(
         long,
-        value_enum,
         env = EnvVars::UV_LINK_MODE,
         help_heading = "Installer options"
     )]
@@ -7796,8 +8167,11 @@ pub struct ResolverArgs {
     no_sources: bool,
 
     /// Don't use sources from the `tool.uv.sources` table for the specified packages [env: `UV_NO_SOURCES_PACKAGE`=]
+    ///
+    /// A package may be followed by a list of extras (e.g., `flask[async]`) to only ignore its
+    /// sources when all of those extras are requested.
     #[arg(long, help_heading = "Resolver options", value_delimiter = ' ')]
-    no_sources_package: Vec<PackageName>,
+    no_sources_package: Vec<NoSourcesPackage>,
 }
 
 /// Arguments that are used by commands that need to resolve and install packages.
@@ -8028,7 +8402,6 @@ pub struct ResolverInstallerArgs {
     /// symlinks with caution.
     #[arg(
         long,
-        value_enum,
         env = EnvVars::UV_LINK_MODE,
         help_heading = "Installer options"
     )]
@@ -8076,8 +8449,11 @@ pub struct ResolverInstallerArgs {
     pub no_sources: bool,
 
     /// Don't use sources from the `tool.uv.sources` table for the specified packages [env: `UV_NO_SOURCES_PACKAGE`=]
+    ///
+    /// A package may be followed by a list of extras (e.g., `flask[async]`) to only ignore its
+    /// sources when all of those extras are requested.
     #[arg(long, help_heading = "Resolver options", value_delimiter = ' ')]
-    pub no_sources_package: Vec<PackageName>,
+    pub no_sources_package: Vec<NoSourcesPackage>,
 }
 
 /// Arguments that are used by commands that need to fetch from the Simple API.
@@ -8266,7 +8642,14 @@ pub struct PublishArgs {
     /// and index upload.
     ///
     /// Defaults to PyPI's publish URL (<https://upload.pypi.org/legacy/>).
-    #[arg(long, env = EnvVars::UV_PUBLISH_URL, hide_env_values = true)]
+    ///
+    /// Also available as `--repository-url`, for compatibility with twine.
+    #[arg(
+        long,
+        alias = "repository-url",
+        env = EnvVars::UV_PUBLISH_URL,
+        hide_env_values = true
+    )]
     pub publish_url: Option<DisplaySafeUrl>,
 
     /// Check an index URL for existing files to skip duplicate uploads.
@@ -8286,7 +8669,12 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL, hide_env_values = true)]
     pub check_url: Option<IndexUrl>,
 
-    #[arg(long, hide = true)]
+    /// Skip files that already exist on the index.
+    ///
+    /// When the index rejects an upload because the file already exists, the file is skipped
+    /// and the remaining files are uploaded. As registries report existing files in different
+    /// ways, prefer `--check-url` where the index provides a simple API.
+    #[arg(long)]
     pub skip_existing: bool,
 
     /// Perform a dry run without uploading files.
@@ -8303,6 +8691,43 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_NO_ATTESTATIONS)]
     pub no_attestations: bool,
 
+    /// Generate PEP 740 attestations for the published files.
+    ///
+    /// Each distribution without an existing `.publish.attestation` file is signed with Sigstore
+    /// using the `pypi-attestations` command, which must be available on the `PATH`. The
+    /// resulting attestations are uploaded alongside the distributions.
+    #[arg(long, conflicts_with = "no_attestations")]
+    pub attestation: bool,
+
+    /// Sign the published files with GPG.
+    ///
+    /// Each distribution is signed with `gpg --detach-sign --armor`, which must be available on
+    /// the `PATH`. The resulting `.asc` signatures are uploaded alongside the distributions.
+    /// Distributions that already have a `.asc` signature next to them are not signed again.
+    #[arg(long)]
+    pub sign: bool,
+
+    /// The GPG key to sign the published files with, e.g., a key fingerprint.
+    ///
+    /// Defaults to the default key of the GPG keyring.
+    #[arg(long, requires = "sign", value_hint = ValueHint::Other)]
+    pub identity: Option<String>,
+
+    /// Validate the metadata of each wheel before uploading.
+    ///
+    /// Each wheel's `METADATA` file is parsed with the same rules used during installation (e.g.,
+    /// every `Requires-Dist` entry must be a valid requirement). If any wheel has invalid
+    /// metadata, the errors are reported and no files are uploaded.
+    #[arg(long)]
+    pub verify_metadata: bool,
+
+    /// List the files that would be uploaded, without uploading them.
+    ///
+    /// Each distribution is validated and displayed with its size and SHA-256 hash. Unlike
+    /// `--dry-run`, no network requests are made, so credentials and the index are not checked.
+    #[arg(long)]
+    pub list: bool,
+
     /// Use direct upload to the registry.
     ///
     /// When enabled, the publish command will use a direct two-phase upload protocol
@@ -30,6 +30,12 @@ pub struct SelfVersionInfo {
     commit_info: Option<CommitInfo>,
     /// The target triple for which uv was built (e.g., `x86_64-unknown-linux-gnu`).
     target_triple: String,
+    /// The version of `rustc` used to build uv (e.g., `1.89.0 (29483883e 2025-08-04)`).
+    ///
+    /// `None` if the version could not be determined at build time.
+    rustc_version: Option<String>,
+    /// Optional Cargo features uv was built with (e.g., `self-update`).
+    features: Vec<String>,
 }
 
 /// Version information for a project (`uv version`).
@@ -133,12 +139,20 @@ pub fn uv_self_version() -> SelfVersionInfo {
 
     // Set by `uv-cli/build.rs`
     let target_triple = env!("RUST_HOST_TARGET").to_string();
+    let rustc_version = option_env_str!("UV_RUSTC_VERSION");
+
+    let mut features = Vec::new();
+    if cfg!(feature = "self-update") {
+        features.push("self-update".to_string());
+    }
 
     SelfVersionInfo {
         package_name: "uv".to_owned(),
         version,
         commit_info,
         target_triple,
+        rustc_version,
+        features,
     }
 }
 
@@ -155,6 +169,8 @@ mod tests {
             version: "0.0.0".to_string(),
             commit_info: None,
             target_triple: "x86_64-unknown-linux-gnu".to_string(),
+            rustc_version: None,
+            features: Vec::new(),
         };
         assert_snapshot!(version, @"0.0.0 (x86_64-unknown-linux-gnu)");
     }
@@ -172,6 +188,8 @@ mod tests {
                 commits_since_last_tag: 0,
             }),
             target_triple: "x86_64-unknown-linux-gnu".to_string(),
+            rustc_version: None,
+            features: Vec::new(),
         };
         assert_snapshot!(version, @"0.0.0 (53b0f5d92 2023-10-19 x86_64-unknown-linux-gnu)");
     }
@@ -189,6 +207,8 @@ mod tests {
                 commits_since_last_tag: 24,
             }),
             target_triple: "x86_64-unknown-linux-gnu".to_string(),
+            rustc_version: None,
+            features: Vec::new(),
         };
         assert_snapshot!(version, @"0.0.0+24 (53b0f5d92 2023-10-19 x86_64-unknown-linux-gnu)");
     }
@@ -206,6 +226,8 @@ mod tests {
                 commits_since_last_tag: 0,
             }),
             target_triple: "x86_64-unknown-linux-gnu".to_string(),
+            rustc_version: Some("1.89.0 (29483883e 2025-08-04)".to_string()),
+            features: vec!["self-update".to_string()],
         };
         assert_json_snapshot!(version, @r#"
         {
@@ -218,7 +240,11 @@ mod tests {
             "last_tag": "v0.0.1",
             "commits_since_last_tag": 0
           },
-          "target_triple": "x86_64-unknown-linux-gnu"
+          "target_triple": "x86_64-unknown-linux-gnu",
+          "rustc_version": "1.89.0 (29483883e 2025-08-04)",
+          "features": [
+            "self-update"
+          ]
         }
         "#);
     }
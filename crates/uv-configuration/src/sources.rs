@@ -1,4 +1,9 @@
-use uv_normalize::PackageName;
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use uv_normalize::{ExtraName, InvalidNameError, PackageName};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
@@ -11,12 +16,117 @@ pub enum NoSources {
     All,
 
     /// Ignore `tool.uv.sources` when resolving dependencies for specific packages.
-    Packages(Vec<PackageName>),
+    Packages(Vec<NoSourcesPackage>),
+}
+
+/// A package for which `tool.uv.sources` should be ignored, optionally scoped to a set of extras
+/// (e.g., `flask` or `flask[async]`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NoSourcesPackage {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The extras that must be active for the sources to be ignored (e.g., `flask[async]`).
+    ///
+    /// If empty, sources are ignored regardless of the active extras.
+    pub extras: Box<[ExtraName]>,
+}
+
+impl From<PackageName> for NoSourcesPackage {
+    fn from(name: PackageName) -> Self {
+        Self {
+            name,
+            extras: Box::default(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NoSourcesPackageError {
+    #[error("Missing closing bracket in `{0}`")]
+    MissingClosingBracket(String),
+    #[error("Unexpected trailing characters after closing bracket in `{0}`")]
+    TrailingCharacters(String),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidNameError),
+}
+
+impl FromStr for NoSourcesPackage {
+    type Err = NoSourcesPackageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, rest)) = s.split_once('[') else {
+            return Ok(Self::from(PackageName::from_str(s.trim())?));
+        };
+        let Some((extras, trailing)) = rest.split_once(']') else {
+            return Err(NoSourcesPackageError::MissingClosingBracket(s.to_string()));
+        };
+        if !trailing.trim().is_empty() {
+            return Err(NoSourcesPackageError::TrailingCharacters(s.to_string()));
+        }
+        let name = PackageName::from_str(name.trim())?;
+        let extras = extras
+            .split(',')
+            .map(str::trim)
+            .filter(|extra| !extra.is_empty())
+            .map(ExtraName::from_str)
+            .collect::<Result<Box<[_]>, _>>()?;
+        Ok(Self { name, extras })
+    }
+}
+
+impl Display for NoSourcesPackage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.extras.is_empty() {
+            write!(f, "[")?;
+            for (i, extra) in self.extras.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{extra}")?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for NoSourcesPackage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for NoSourcesPackage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for NoSourcesPackage {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NoSourcesPackage")
+    }
+
+    fn json_schema(_gen: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "The name of a package, optionally followed by a list of extras (e.g., `flask[async]`).",
+        })
+    }
 }
 
 impl NoSources {
     /// Determine the no sources strategy to use for the given arguments.
-    pub fn from_args(no_sources: Option<bool>, no_sources_package: Vec<PackageName>) -> Self {
+    pub fn from_args(no_sources: Option<bool>, no_sources_package: Vec<NoSourcesPackage>) -> Self {
         match no_sources {
             Some(true) => Self::All,
             Some(false) => Self::None,
@@ -35,12 +145,29 @@ impl NoSources {
         matches!(self, Self::All)
     }
 
-    /// Returns `true` if sources should be ignored for the given package.
+    /// Returns `true` if sources should be ignored for the given package, regardless of the
+    /// active extras.
     pub fn for_package(&self, package_name: &PackageName) -> bool {
+        self.for_package_with_extras(package_name, &[])
+    }
+
+    /// Returns `true` if sources should be ignored for the given package, when requested with
+    /// the given extras.
+    ///
+    /// Entries without extras match the package regardless of the active extras; entries with
+    /// extras only match if all of their extras are active.
+    pub fn for_package_with_extras(
+        &self,
+        package_name: &PackageName,
+        extras: &[ExtraName],
+    ) -> bool {
         match self {
             Self::None => false,
             Self::All => true,
-            Self::Packages(packages) => packages.contains(package_name),
+            Self::Packages(packages) => packages.iter().any(|package| {
+                package.name == *package_name
+                    && package.extras.iter().all(|extra| extras.contains(extra))
+            }),
         }
     }
 
@@ -64,3 +191,55 @@ impl NoSources {
         matches!(self, Self::None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn for_package_name_only() {
+        let flask = PackageName::from_str("flask").unwrap();
+        let django = PackageName::from_str("django").unwrap();
+        let extra = ExtraName::from_str("async").unwrap();
+
+        let no_sources = NoSources::from_args(None, vec![NoSourcesPackage::from(flask.clone())]);
+        assert!(no_sources.for_package(&flask));
+        assert!(no_sources.for_package_with_extras(&flask, std::slice::from_ref(&extra)));
+        assert!(!no_sources.for_package(&django));
+        assert!(!no_sources.for_package_with_extras(&django, &[extra]));
+    }
+
+    #[test]
+    fn for_package_extra_scoped() {
+        let flask = PackageName::from_str("flask").unwrap();
+        let extra = ExtraName::from_str("async").unwrap();
+        let other = ExtraName::from_str("dotenv").unwrap();
+
+        let no_sources = NoSources::from_args(
+            None,
+            vec![NoSourcesPackage::from_str("flask[async]").unwrap()],
+        );
+
+        // Sources are only ignored when the extra is active.
+        assert!(!no_sources.for_package(&flask));
+        assert!(!no_sources.for_package_with_extras(&flask, std::slice::from_ref(&other)));
+        assert!(no_sources.for_package_with_extras(&flask, std::slice::from_ref(&extra)));
+        assert!(no_sources.for_package_with_extras(&flask, &[other, extra]));
+    }
+
+    #[test]
+    fn parse_no_sources_package() {
+        let package = NoSourcesPackage::from_str("flask").unwrap();
+        assert_eq!(package.to_string(), "flask");
+        assert!(package.extras.is_empty());
+
+        let package = NoSourcesPackage::from_str("Flask[async, dotenv]").unwrap();
+        assert_eq!(package.to_string(), "flask[async,dotenv]");
+
+        assert!(NoSourcesPackage::from_str("flask[async").is_err());
+        assert!(NoSourcesPackage::from_str("flask[async]x").is_err());
+        assert!(NoSourcesPackage::from_str("flask>=1.0").is_err());
+    }
+}
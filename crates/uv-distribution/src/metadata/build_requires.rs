@@ -126,7 +126,7 @@ impl BuildRequires {
         // Lower the requirements.
         let mut requires_dist = Vec::new();
         for requirement in metadata.requires_dist {
-            if sources.for_package(&requirement.name) {
+            if sources.for_package_with_extras(&requirement.name, &requirement.extras) {
                 requires_dist.push(Requirement::from(requirement));
                 continue;
             }
@@ -202,7 +202,7 @@ impl BuildRequires {
         // Lower the requirements.
         let mut requires_dist = Vec::new();
         for requirement in metadata.requires_dist {
-            if sources.for_package(&requirement.name) {
+            if sources.for_package_with_extras(&requirement.name, &requirement.extras) {
                 requires_dist.push(Requirement::from(requirement));
                 continue;
             }
@@ -145,7 +145,7 @@ impl SourcedDependencyGroups {
         for (name, group) in dependency_groups {
             let mut requirements = Vec::new();
             for requirement in group.requirements {
-                if no_sources.for_package(&requirement.name) {
+                if no_sources.for_package_with_extras(&requirement.name, &requirement.extras) {
                     requirements.push(Requirement::from(requirement));
                     continue;
                 }
@@ -149,7 +149,7 @@ impl RequiresDist {
         for (name, flat_group) in dependency_groups {
             let mut requirements = Vec::new();
             for requirement in flat_group.requirements {
-                if no_sources.for_package(&requirement.name) {
+                if no_sources.for_package_with_extras(&requirement.name, &requirement.extras) {
                     requirements.push(Requirement::from(requirement));
                     continue;
                 }
@@ -193,7 +193,7 @@ impl RequiresDist {
         // Lower the requirements.
         let mut requires_dist = Vec::new();
         for requirement in Box::into_iter(metadata.requires_dist) {
-            if no_sources.for_package(&requirement.name) {
+            if no_sources.for_package_with_extras(&requirement.name, &requirement.extras) {
                 requires_dist.push(Requirement::from(requirement));
                 continue;
             }
@@ -3,10 +3,12 @@
 
 use std::io;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
 use rustc_hash::FxHashMap;
 use tracing::debug;
+use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 use walkdir::WalkDir;
 
@@ -15,17 +17,16 @@ use walkdir::WalkDir;
 /// Defaults to [`LinkMode::Clone`] on macOS and Linux (which support copy-on-write on
 /// APFS and btrfs/xfs/bcachefs respectively), and [`LinkMode::Hardlink`] on other
 /// platforms.
+///
+/// Parsing (e.g., of `--link-mode`, `UV_LINK_MODE`, or the `link-mode` setting) goes through the
+/// [`FromStr`] implementation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(deny_unknown_fields, rename_all = "kebab-case")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum LinkMode {
     /// Clone (i.e., copy-on-write) packages from the source into the destination.
-    #[cfg_attr(feature = "serde", serde(alias = "reflink"))]
     #[cfg_attr(feature = "clap", value(alias = "reflink"))]
     Clone,
     /// Copy packages from the source into the destination.
@@ -55,8 +56,73 @@ impl LinkMode {
     pub fn is_symlink(&self) -> bool {
         matches!(self, Self::Symlink)
     }
+
+    /// Read the [`LinkMode`] from the `UV_LINK_MODE` environment variable.
+    ///
+    /// Returns `None` if the variable is unset or empty.
+    pub fn from_env() -> Result<Option<Self>, LinkModeParseError> {
+        match std::env::var(EnvVars::UV_LINK_MODE) {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Self::from_str(&value).map(Some),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(std::env::VarError::NotUnicode(value)) => {
+                Err(LinkModeParseError(value.to_string_lossy().into_owned()))
+            }
+        }
+    }
 }
 
+impl FromStr for LinkMode {
+    type Err = LinkModeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clone" | "reflink" => Ok(Self::Clone),
+            "copy" => Ok(Self::Copy),
+            "hardlink" => Ok(Self::Hardlink),
+            "symlink" => Ok(Self::Symlink),
+            _ => Err(LinkModeParseError(s.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LinkMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::from_str(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Parse `--link-mode` (and `UV_LINK_MODE`) with [`FromStr`], while retaining the possible values
+/// (and the `reflink` alias) from [`clap::ValueEnum`] for the help output.
+#[cfg(feature = "clap")]
+impl clap::builder::ValueParserFactory for LinkMode {
+    type Parser = clap::builder::TryMapValueParser<
+        clap::builder::PossibleValuesParser,
+        fn(String) -> Result<Self, LinkModeParseError>,
+    >;
+
+    fn value_parser() -> Self::Parser {
+        use clap::builder::TypedValueParser;
+
+        clap::builder::PossibleValuesParser::new(
+            <Self as clap::ValueEnum>::value_variants()
+                .iter()
+                .filter_map(clap::ValueEnum::to_possible_value),
+        )
+        .try_map(|value| Self::from_str(&value))
+    }
+}
+
+/// An invalid [`LinkMode`], e.g., from `UV_LINK_MODE` or the `link-mode` setting.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid link mode `{0}`; expected one of: `clone`, `copy`, `hardlink`, `symlink`")]
+pub struct LinkModeParseError(String);
+
 /// Behavior when the destination directory already exists.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum OnExistingDirectory {
@@ -124,6 +190,16 @@ impl CopyLocks {
         // Copy the file, which will also set its permissions.
         fs_err::copy(from, to)?;
 
+        // Depending on the umask and filesystem, `copy` may not carry over the executable bit, so
+        // explicitly apply the source's mode to the destination.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = fs_err::metadata(from)?.permissions().mode();
+            fs_err::set_permissions(to, std::fs::Permissions::from_mode(mode))?;
+        }
+
         Ok(())
     }
 }
@@ -140,6 +216,8 @@ pub struct LinkOptions<'a, F = fn(&Path) -> bool> {
     copy_locks: Option<&'a CopyLocks>,
     /// What to do when the destination directory already exists.
     on_existing_directory: OnExistingDirectory,
+    /// Whether to skip the user-facing warning when falling back to a slower [`LinkMode`].
+    suppress_fallback_warnings: bool,
 }
 
 impl LinkOptions<'static> {
@@ -150,6 +228,7 @@ impl LinkOptions<'static> {
             needs_mutable_copy: |_| false,
             copy_locks: None,
             on_existing_directory: OnExistingDirectory::default(),
+            suppress_fallback_warnings: false,
         }
     }
 }
@@ -174,6 +253,7 @@ impl<'a, F> LinkOptions<'a, F> {
             needs_mutable_copy: f,
             copy_locks: self.copy_locks,
             on_existing_directory: self.on_existing_directory,
+            suppress_fallback_warnings: self.suppress_fallback_warnings,
         }
     }
 
@@ -188,6 +268,7 @@ impl<'a, F> LinkOptions<'a, F> {
             needs_mutable_copy: self.needs_mutable_copy,
             copy_locks: Some(locks),
             on_existing_directory: self.on_existing_directory,
+            suppress_fallback_warnings: self.suppress_fallback_warnings,
         }
     }
 
@@ -199,6 +280,21 @@ impl<'a, F> LinkOptions<'a, F> {
             needs_mutable_copy: self.needs_mutable_copy,
             copy_locks: self.copy_locks,
             on_existing_directory,
+            suppress_fallback_warnings: self.suppress_fallback_warnings,
+        }
+    }
+
+    /// Set whether to suppress the warnings emitted when falling back to a slower [`LinkMode`].
+    ///
+    /// The fallback itself is unaffected; only the user-facing warning is skipped.
+    #[must_use]
+    pub fn with_suppress_fallback_warnings(self, suppress_fallback_warnings: bool) -> Self {
+        LinkOptions {
+            mode: self.mode,
+            needs_mutable_copy: self.needs_mutable_copy,
+            copy_locks: self.copy_locks,
+            on_existing_directory: self.on_existing_directory,
+            suppress_fallback_warnings,
         }
     }
 
@@ -308,6 +404,13 @@ pub enum LinkError {
         #[source]
         err: io::Error,
     },
+    #[error("Failed to hard link `{}` to `{}`", from.display(), to.display())]
+    Link {
+        from: PathBuf,
+        to: PathBuf,
+        #[source]
+        err: io::Error,
+    },
     #[error("Failed to create symlink from `{}` to `{}`", from.display(), to.display())]
     Symlink {
         from: PathBuf,
@@ -456,6 +559,50 @@ fn reflink_with_permissions(from: &Path, to: &Path) -> io::Result<()> {
     reflink_copy::reflink(from, to)
 }
 
+/// Return a hint for the hardlink fallback warning if the filesystem backing `path` is a common
+/// cause of hardlink failures.
+///
+/// In containers (e.g., Alpine-based images), the target directory is often on an overlay or
+/// tmpfs filesystem while the cache lives on a separate volume, so hardlinks between them fail.
+/// Detection is best-effort: any error while querying the filesystem yields no hint.
+#[cfg(target_os = "linux")]
+fn filesystem_hint(path: &Path) -> Option<&'static str> {
+    let stat = rustix::fs::statfs(path.parent().unwrap_or(path)).ok()?;
+    // The width and signedness of `f_type` vary by architecture, but the magic numbers we
+    // recognize all fit in a `u32`.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::unnecessary_cast
+    )]
+    let f_type = stat.f_type as u32;
+    filesystem_type_hint(f_type)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn filesystem_hint(_path: &Path) -> Option<&'static str> {
+    None
+}
+
+/// Return a hint for the hardlink fallback warning given a `statfs` filesystem type.
+#[cfg(any(target_os = "linux", test))]
+fn filesystem_type_hint(f_type: u32) -> Option<&'static str> {
+    /// See `OVERLAYFS_SUPER_MAGIC` in `linux/magic.h`.
+    const OVERLAYFS_SUPER_MAGIC: u32 = 0x794c_7630;
+    /// See `TMPFS_MAGIC` in `linux/magic.h`.
+    const TMPFS_MAGIC: u32 = 0x0102_1994;
+
+    match f_type {
+        OVERLAYFS_SUPER_MAGIC => Some(
+            "The target directory is on an overlay filesystem (e.g., in a container); place the cache on the same volume as the target directory with `UV_CACHE_DIR` to enable hardlinking.",
+        ),
+        TMPFS_MAGIC => Some(
+            "The target directory is on a tmpfs filesystem; place the cache on the same tmpfs with `UV_CACHE_DIR` to enable hardlinking.",
+        ),
+        _ => None,
+    }
+}
+
 /// Attempt to reflink a single file, falling back via [`link_file`] on failure.
 fn reflink_file_with_fallback<F>(
     path: &Path,
@@ -662,11 +809,16 @@ where
                         target.display(),
                         err
                     );
-                    warn_user_once!(
-                        "Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         \
-                        If the cache and target directories are on different filesystems, hardlinking may not be supported.\n         \
-                        If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
-                    );
+                    if !options.suppress_fallback_warnings {
+                        warn_user_once!(
+                            "Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         \
+                            If the cache and target directories are on different filesystems, hardlinking may not be supported.{}\n         \
+                            If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.",
+                            filesystem_hint(target)
+                                .map(|hint| format!("\n         {hint}"))
+                                .unwrap_or_default()
+                        );
+                    }
                     link_file(path, target, state.next_mode(), options)
                 }
             } else {
@@ -680,7 +832,11 @@ where
                 {
                     atomic_hardlink_overwrite(path, target, state, options)
                 } else {
-                    Err(LinkError::Io(err))
+                    Err(LinkError::Link {
+                        from: path.to_path_buf(),
+                        to: target.to_path_buf(),
+                        err,
+                    })
                 }
             } else {
                 Ok(state)
@@ -721,11 +877,13 @@ where
                         target.display(),
                         err
                     );
-                    warn_user_once!(
-                        "Failed to symlink files; falling back to full copy. This may lead to degraded performance.\n         \
-                        If the cache and target directories are on different filesystems, symlinking may not be supported.\n         \
-                        If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
-                    );
+                    if !options.suppress_fallback_warnings {
+                        warn_user_once!(
+                            "Failed to symlink files; falling back to full copy. This may lead to degraded performance.\n         \
+                            If the cache and target directories are on different filesystems, symlinking may not be supported.\n         \
+                            If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
+                        );
+                    }
                     link_file(path, target, state.next_mode(), options)
                 }
             } else {
@@ -817,11 +975,16 @@ where
             "Failed to hardlink `{}` to temp location, falling back to copy",
             src.display()
         );
-        warn_user_once!(
-            "Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         \
-            If the cache and target directories are on different filesystems, hardlinking may not be supported.\n         \
-            If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
-        );
+        if !options.suppress_fallback_warnings {
+            warn_user_once!(
+                "Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         \
+                If the cache and target directories are on different filesystems, hardlinking may not be supported.{}\n         \
+                If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.",
+                filesystem_hint(dst)
+                    .map(|hint| format!("\n         {hint}"))
+                    .unwrap_or_default()
+            );
+        }
         let state = state.next_mode();
         atomic_copy_overwrite(src, dst, options)?;
         Ok(state)
@@ -877,11 +1040,13 @@ where
             "Failed to symlink `{}` to temp location, falling back to copy",
             src.display()
         );
-        warn_user_once!(
-            "Failed to symlink files; falling back to full copy. This may lead to degraded performance.\n         \
-            If the cache and target directories are on different filesystems, symlinking may not be supported.\n         \
-            If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
-        );
+        if !options.suppress_fallback_warnings {
+            warn_user_once!(
+                "Failed to symlink files; falling back to full copy. This may lead to degraded performance.\n         \
+                If the cache and target directories are on different filesystems, symlinking may not be supported.\n         \
+                If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
+            );
+        }
         let state = state.next_mode();
         atomic_copy_overwrite(src, dst, options)?;
         Ok(state)
@@ -983,6 +1148,23 @@ mod tests {
         verify_test_tree(dst_dir.path());
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_synchronized_copy_preserves_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = test_tempdir();
+        let src = dir.path().join("script");
+        let dst = dir.path().join("copied");
+        fs_err::write(&src, "#!/bin/sh\n").unwrap();
+        fs_err::set_permissions(&src, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        CopyLocks::default().synchronized_copy(&src, &dst).unwrap();
+
+        let mode = fs_err::metadata(&dst).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+    }
+
     #[test]
     fn test_hardlink_dir_basic() {
         let src_dir = test_tempdir();
@@ -1381,6 +1563,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hardlink_subsequent_error_includes_paths() {
+        let src_dir = test_tempdir();
+        let dst_dir = test_tempdir();
+
+        let src = src_dir.path().join("file.txt");
+        let dst = dst_dir.path().join("file.txt");
+        fs_err::write(&src, "content").unwrap();
+        fs_err::write(&dst, "existing").unwrap();
+
+        // Once hard linking is confirmed to work, a conflicting file is an error rather than a
+        // fallback to copy.
+        let options = LinkOptions::new(LinkMode::Hardlink)
+            .with_on_existing_directory(OnExistingDirectory::Fail);
+        let state = LinkState::new(LinkMode::Hardlink).mode_working();
+        let err = hardlink_file_with_fallback(&src, &dst, state, &options).unwrap_err();
+
+        assert!(matches!(err, LinkError::Link { .. }));
+        assert!(err.to_string().contains(&dst.display().to_string()));
+    }
+
+    #[test]
+    fn test_suppress_fallback_warnings() {
+        let src_dir = test_tempdir();
+        let dst_dir = test_tempdir();
+
+        let src = src_dir.path().join("file.txt");
+        let dst = dst_dir.path().join("file.txt");
+        fs_err::write(&src, "content").unwrap();
+
+        uv_warnings::enable();
+        let warned = || {
+            uv_warnings::WARNINGS
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|warning| warning.starts_with("Failed to hardlink files"))
+        };
+
+        // A conflicting file makes the initial hard link fail, forcing a fallback to copy, which
+        // must not warn when fallback warnings are suppressed.
+        fs_err::write(&dst, "existing").unwrap();
+        let options = LinkOptions::new(LinkMode::Hardlink).with_suppress_fallback_warnings(true);
+        let state =
+            hardlink_file_with_fallback(&src, &dst, LinkState::new(LinkMode::Hardlink), &options)
+                .unwrap();
+        assert_eq!(state.mode, LinkMode::Copy);
+        assert_eq!(fs_err::read_to_string(&dst).unwrap(), "content");
+        assert!(!warned());
+
+        // Otherwise, the same fallback warns.
+        fs_err::write(&dst, "existing").unwrap();
+        let options = LinkOptions::new(LinkMode::Hardlink);
+        let state =
+            hardlink_file_with_fallback(&src, &dst, LinkState::new(LinkMode::Hardlink), &options)
+                .unwrap();
+        assert_eq!(state.mode, LinkMode::Copy);
+        assert!(warned());
+    }
+
     #[test]
     fn test_copy_mode_overwrites_in_fail_mode() {
         let src_dir = test_tempdir();
@@ -1891,6 +2133,51 @@ mod tests {
         // If symlink failed (permissions), that's expected on Windows without elevation
     }
 
+    #[test]
+    fn test_link_mode_from_str() {
+        assert_eq!(LinkMode::from_str("clone").unwrap(), LinkMode::Clone);
+        assert_eq!(LinkMode::from_str("reflink").unwrap(), LinkMode::Clone);
+        assert_eq!(LinkMode::from_str("copy").unwrap(), LinkMode::Copy);
+        assert_eq!(LinkMode::from_str("hardlink").unwrap(), LinkMode::Hardlink);
+        assert_eq!(LinkMode::from_str("symlink").unwrap(), LinkMode::Symlink);
+
+        let err = LinkMode::from_str("hard-link").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid link mode `hard-link`; expected one of: `clone`, `copy`, `hardlink`, `symlink`"
+        );
+    }
+
+    #[test]
+    fn test_link_mode_from_env() {
+        temp_env::with_var_unset(EnvVars::UV_LINK_MODE, || {
+            assert_eq!(LinkMode::from_env().unwrap(), None);
+        });
+        temp_env::with_var(EnvVars::UV_LINK_MODE, Some(""), || {
+            assert_eq!(LinkMode::from_env().unwrap(), None);
+        });
+
+        for (value, expected) in [
+            ("clone", LinkMode::Clone),
+            ("reflink", LinkMode::Clone),
+            ("copy", LinkMode::Copy),
+            ("hardlink", LinkMode::Hardlink),
+            ("symlink", LinkMode::Symlink),
+        ] {
+            temp_env::with_var(EnvVars::UV_LINK_MODE, Some(value), || {
+                assert_eq!(LinkMode::from_env().unwrap(), Some(expected));
+            });
+        }
+
+        temp_env::with_var(EnvVars::UV_LINK_MODE, Some("hard-link"), || {
+            let err = LinkMode::from_env().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid link mode `hard-link`; expected one of: `clone`, `copy`, `hardlink`, `symlink`"
+            );
+        });
+    }
+
     #[test]
     fn test_link_state_new() {
         let state = LinkState::new(LinkMode::Clone);
@@ -1993,4 +2280,18 @@ mod tests {
         );
         verify_test_tree(dst_dir.path());
     }
+
+    #[test]
+    fn test_filesystem_type_hint() {
+        // overlayfs
+        let hint = filesystem_type_hint(0x794c_7630).unwrap();
+        assert!(hint.contains("overlay filesystem"), "{hint}");
+        // tmpfs
+        let hint = filesystem_type_hint(0x0102_1994).unwrap();
+        assert!(hint.contains("tmpfs filesystem"), "{hint}");
+        // ext4
+        assert_eq!(filesystem_type_hint(0xef53), None);
+        // btrfs
+        assert_eq!(filesystem_type_hint(0x9123_683e), None);
+    }
 }
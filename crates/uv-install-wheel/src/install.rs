@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use fs_err::File;
-use tracing::{instrument, trace};
+use tracing::{debug, instrument, trace};
 
 use uv_distribution_filename::WheelFilename;
 use uv_pep440::Version;
@@ -89,8 +89,15 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     // > 1.c If Root-Is-Purelib == ‘true’, unpack archive into purelib (site-packages).
     // > 1.d Else unpack archive into platlib (site-packages).
     trace!(?name, "Extracting wheel files");
-    link_wheel_files(link_mode, site_packages, wheel, state, filename)?;
-    trace!(?name, "Extracted wheel files");
+    let outcome = link_wheel_files(link_mode, site_packages, wheel, state, filename)?;
+    if outcome.effective_mode == link_mode {
+        trace!(?name, "Extracted wheel files");
+    } else {
+        debug!(
+            "Extracted wheel files for `{name}` using {:?} instead of the requested {:?}",
+            outcome.effective_mode, link_mode
+        );
+    }
 
     // Read the RECORD file.
     let mut record_file = File::open(wheel.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
@@ -164,3 +171,117 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
 
     Ok(())
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+    use indoc::indoc;
+
+    use uv_distribution_filename::WheelFilename;
+    use uv_pypi_types::Scheme;
+
+    use super::install_wheel;
+    use crate::Layout;
+    use crate::linker::{InstallState, LinkMode};
+
+    /// Files under the `.data` directory must be routed to their scheme paths, regardless of the
+    /// link mode.
+    #[test]
+    fn install_data_categories() -> Result<()> {
+        let filename = WheelFilename::from_str("foo-1.0.0-py3-none-any.whl")?;
+
+        for link_mode in [
+            LinkMode::Clone,
+            LinkMode::Copy,
+            LinkMode::Hardlink,
+            LinkMode::Symlink,
+        ] {
+            let temp_dir = assert_fs::TempDir::new()?;
+
+            // Create an unpacked wheel with a script, a header, and a data file.
+            let wheel = temp_dir.child("wheel");
+            wheel.child("foo/__init__.py").write_str("")?;
+            wheel
+                .child("foo-1.0.0.data/scripts/foo-script")
+                .write_str("#!python\nprint('foo')\n")?;
+            wheel
+                .child("foo-1.0.0.data/headers/foo.h")
+                .write_str("int foo(void);\n")?;
+            wheel
+                .child("foo-1.0.0.data/data/share/foo/foo.txt")
+                .write_str("foo\n")?;
+            wheel
+                .child("foo-1.0.0.dist-info/METADATA")
+                .write_str(indoc! {"
+                    Metadata-Version: 2.1
+                    Name: foo
+                    Version: 1.0.0
+                "})?;
+            wheel
+                .child("foo-1.0.0.dist-info/WHEEL")
+                .write_str(indoc! {"
+                    Wheel-Version: 1.0
+                    Generator: test
+                    Root-Is-Purelib: true
+                    Tag: py3-none-any
+                "})?;
+            wheel
+                .child("foo-1.0.0.dist-info/RECORD")
+                .write_str(indoc! {"
+                    foo/__init__.py,,
+                    foo-1.0.0.data/scripts/foo-script,,
+                    foo-1.0.0.data/headers/foo.h,,
+                    foo-1.0.0.data/data/share/foo/foo.txt,,
+                    foo-1.0.0.dist-info/METADATA,,
+                    foo-1.0.0.dist-info/WHEEL,,
+                    foo-1.0.0.dist-info/RECORD,,
+                "})?;
+
+            // Something that looks sufficiently like a Unix environment.
+            let venv = temp_dir.child("venv");
+            let site_packages = venv.child("lib/python3.12/site-packages");
+            site_packages.create_dir_all()?;
+            let layout = Layout {
+                sys_executable: venv.path().join("bin/python"),
+                python_version: (3, 12),
+                os_name: "posix".to_string(),
+                scheme: Scheme {
+                    purelib: site_packages.to_path_buf(),
+                    platlib: site_packages.to_path_buf(),
+                    scripts: venv.path().join("bin"),
+                    data: venv.path().to_path_buf(),
+                    include: venv.path().join("include/python3.12"),
+                },
+            };
+
+            install_wheel::<(), ()>(
+                &layout,
+                false,
+                wheel.path(),
+                &filename,
+                None,
+                None,
+                None,
+                None,
+                false,
+                link_mode,
+                &InstallState::default(),
+            )?;
+
+            site_packages.child("foo/__init__.py").assert("");
+            // The `#!python` shebang is rewritten to point to the interpreter.
+            let script = fs_err::read_to_string(venv.child("bin/foo-script"))?;
+            assert!(script.starts_with("#!"));
+            assert!(script.ends_with("\nprint('foo')\n"));
+            venv.child("include/python3.12/foo/foo.h")
+                .assert("int foo(void);\n");
+            venv.child("share/foo/foo.txt").assert("foo\n");
+            assert!(!site_packages.child("foo-1.0.0.data").exists());
+        }
+
+        Ok(())
+    }
+}
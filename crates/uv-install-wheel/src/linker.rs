@@ -29,6 +29,8 @@ pub struct InstallState {
     site_packages_paths: Mutex<FxHashMap<PathBuf, BTreeSet<(WheelFilename, PathBuf)>>>,
     /// Preview settings for feature flags.
     preview: Preview,
+    /// Whether to suppress the warnings emitted when linking falls back to a slower mode.
+    suppress_fallback_warnings: bool,
 }
 
 impl InstallState {
@@ -38,14 +40,33 @@ impl InstallState {
             locks: CopyLocks::default(),
             site_packages_paths: Mutex::new(FxHashMap::default()),
             preview,
+            suppress_fallback_warnings: false,
         }
     }
 
+    /// Set whether to suppress the warnings emitted when linking falls back to a slower mode,
+    /// e.g., when hard linking fails and files are copied instead.
+    #[must_use]
+    pub fn with_suppress_fallback_warnings(mut self, suppress_fallback_warnings: bool) -> Self {
+        self.suppress_fallback_warnings = suppress_fallback_warnings;
+        self
+    }
+
     /// Get the underlying copy locks for use with [`uv_fs::link::link_dir`] functions.
     fn copy_locks(&self) -> &CopyLocks {
         &self.locks
     }
 
+    /// Forget the paths registered by previous installations, retaining the copy locks.
+    ///
+    /// The conflict detection in [`InstallState::warn_package_conflicts`] assumes all wheels are
+    /// installed into the same site-packages directory. Call this method before reusing the state
+    /// to install into another directory, to avoid reporting packages from different
+    /// environments as conflicting.
+    pub fn reset(&self) {
+        self.site_packages_paths.lock().unwrap().clear();
+    }
+
     /// Register which package installs which (top level) path.
     ///
     /// This is later used warn when different files at the same path exist in multiple packages.
@@ -232,11 +253,19 @@ impl InstallState {
                 format!("* {} ({})", wheel_filename.name, wheel_filename)
             })
             .join("\n");
+        // Module names are not guaranteed to be valid UTF-8, in which case we can only show a
+        // lossy rendering of the path.
+        let note = if file.to_str().is_none() {
+            " (the path is not valid UTF-8 and is displayed lossily)"
+        } else {
+            ""
+        };
         warn_user!(
-            "The file `{}` is provided by more than one package, \
+            "The file `{}`{} is provided by more than one package, \
             which causes an install race condition and can result in a broken module. \
             Packages containing the file:\n{}",
             file.user_display(),
+            note,
             packages
         );
 
@@ -247,6 +276,14 @@ impl InstallState {
     }
 }
 
+/// The result of linking a wheel's files into site packages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LinkOutcome {
+    /// The [`LinkMode`] that was actually used, which may differ from the requested mode if a
+    /// fallback was needed, e.g., [`LinkMode::Copy`] if cloning and hard linking both failed.
+    pub(crate) effective_mode: LinkMode,
+}
+
 /// Extract a wheel by linking all of its files into site packages.
 #[instrument(skip_all)]
 pub(crate) fn link_wheel_files(
@@ -255,7 +292,7 @@ pub(crate) fn link_wheel_files(
     wheel: impl AsRef<Path>,
     state: &InstallState,
     filename: &WheelFilename,
-) -> Result<(), Error> {
+) -> Result<LinkOutcome, Error> {
     let wheel = wheel.as_ref();
     let site_packages = site_packages.as_ref();
     register_installed_paths(wheel, state, filename)?;
@@ -265,7 +302,8 @@ pub(crate) fn link_wheel_files(
     let options = LinkOptions::new(link_mode)
         .with_mutable_copy_filter(|p: &Path| p.ends_with("RECORD"))
         .with_copy_locks(state.copy_locks())
-        .with_on_existing_directory(OnExistingDirectory::Merge);
+        .with_on_existing_directory(OnExistingDirectory::Merge)
+        .with_suppress_fallback_warnings(state.suppress_fallback_warnings);
     let used_link_mode = link_dir(wheel, site_packages, &options)?;
 
     if used_link_mode == LinkMode::Clone {
@@ -278,7 +316,9 @@ pub(crate) fn link_wheel_files(
         update_site_packages_mtime(site_packages);
     }
 
-    Ok(())
+    Ok(LinkOutcome {
+        effective_mode: used_link_mode,
+    })
 }
 
 /// Update the mtime of the site-packages directory to the current time.
@@ -314,3 +354,175 @@ fn register_installed_paths(
     }
     Ok(())
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+
+    use uv_distribution_filename::WheelFilename;
+
+    use super::{InstallState, LinkMode, link_wheel_files};
+
+    #[test]
+    fn warn_directory_conflict_non_utf8() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let module = OsStr::from_bytes(b"mod\xff");
+
+        let wheel_a = WheelFilename::from_str("a-1.0.0-py3-none-any.whl")?;
+        let wheel_b = WheelFilename::from_str("b-1.0.0-py3-none-any.whl")?;
+
+        let unpacked_a = temp_dir.child("a").join(module);
+        fs_err::create_dir_all(&unpacked_a)?;
+        fs_err::write(unpacked_a.join("__init__.py"), "a = 1\n")?;
+        let unpacked_b = temp_dir.child("b").join(module);
+        fs_err::create_dir_all(&unpacked_b)?;
+        fs_err::write(unpacked_b.join("__init__.py"), "b = 22\n")?;
+
+        let state = InstallState::default();
+        state.register_installed_path(Path::new(module), &unpacked_a, &wheel_a);
+        state.register_installed_path(Path::new(module), &unpacked_b, &wheel_b);
+
+        let site_packages_paths = state.site_packages_paths.lock().unwrap();
+        let wheels = &site_packages_paths[Path::new(module)];
+        assert_eq!(wheels.len(), 2);
+        assert!(InstallState::warn_directory_conflict(
+            Path::new(module),
+            wheels
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reset_between_targets() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let wheel_a = WheelFilename::from_str("a-1.0.0-py3-none-any.whl")?;
+        let wheel_b = WheelFilename::from_str("b-1.0.0-py3-none-any.whl")?;
+
+        // Two wheels providing the same module with different contents.
+        let unpacked_a = temp_dir.child("a");
+        unpacked_a
+            .child("module")
+            .child("__init__.py")
+            .write_str("a = 1\n")?;
+        let unpacked_b = temp_dir.child("b");
+        unpacked_b
+            .child("module")
+            .child("__init__.py")
+            .write_str("b = 22\n")?;
+
+        let target_a = temp_dir.child("target-a");
+        target_a.create_dir_all()?;
+        let target_b = temp_dir.child("target-b");
+        target_b.create_dir_all()?;
+
+        // Install each wheel into a separate target, reusing the same state.
+        let state = InstallState::default();
+        link_wheel_files(LinkMode::Copy, &target_a, &unpacked_a, &state, &wheel_a)?;
+        state.reset();
+        link_wheel_files(LinkMode::Copy, &target_b, &unpacked_b, &state, &wheel_b)?;
+
+        // Only the module from the second target is tracked, so there's no conflict.
+        let site_packages_paths = state.site_packages_paths.lock().unwrap();
+        let wheels = &site_packages_paths[Path::new("module")];
+        assert_eq!(wheels.len(), 1);
+        assert!(!InstallState::warn_directory_conflict(
+            Path::new("module"),
+            wheels
+        )?);
+
+        target_b
+            .child("module")
+            .child("__init__.py")
+            .assert("b = 22\n");
+
+        Ok(())
+    }
+
+    /// Cloning into a different filesystem fails for both reflinks and hard links, so the
+    /// reported mode must reflect the fallback to copying.
+    #[test]
+    #[expect(clippy::print_stderr)]
+    fn effective_mode_after_clone_fallback() -> Result<()> {
+        let Ok(alt_fs) = std::env::var(uv_static::EnvVars::UV_INTERNAL__TEST_ALT_FS) else {
+            eprintln!("Skipping: UV_INTERNAL__TEST_ALT_FS not set");
+            return Ok(());
+        };
+        fs_err::create_dir_all(&alt_fs)?;
+        let temp_dir = assert_fs::TempDir::new()?;
+        let alt_dir = assert_fs::TempDir::new_in(alt_fs)?;
+
+        let wheel = WheelFilename::from_str("a-1.0.0-py3-none-any.whl")?;
+        let unpacked = alt_dir.child("a");
+        unpacked
+            .child("module")
+            .child("__init__.py")
+            .write_str("a = 1\n")?;
+
+        let site_packages = temp_dir.child("site-packages");
+        site_packages.create_dir_all()?;
+
+        let state = InstallState::default();
+        let outcome = link_wheel_files(LinkMode::Clone, &site_packages, &unpacked, &state, &wheel)?;
+        assert_eq!(outcome.effective_mode, LinkMode::Copy);
+
+        site_packages
+            .child("module")
+            .child("__init__.py")
+            .assert("a = 1\n");
+
+        Ok(())
+    }
+
+    /// With fallback warnings suppressed, falling back from hard linking to copying across
+    /// filesystems must not emit a warning.
+    #[test]
+    #[expect(clippy::print_stderr)]
+    fn suppress_fallback_warnings() -> Result<()> {
+        let Ok(alt_fs) = std::env::var(uv_static::EnvVars::UV_INTERNAL__TEST_ALT_FS) else {
+            eprintln!("Skipping: UV_INTERNAL__TEST_ALT_FS not set");
+            return Ok(());
+        };
+        fs_err::create_dir_all(&alt_fs)?;
+        let temp_dir = assert_fs::TempDir::new()?;
+        let alt_dir = assert_fs::TempDir::new_in(alt_fs)?;
+
+        let wheel = WheelFilename::from_str("a-1.0.0-py3-none-any.whl")?;
+        let unpacked = alt_dir.child("a");
+        unpacked
+            .child("module")
+            .child("__init__.py")
+            .write_str("a = 1\n")?;
+
+        let site_packages = temp_dir.child("site-packages");
+        site_packages.create_dir_all()?;
+
+        uv_warnings::enable();
+        let state = InstallState::default().with_suppress_fallback_warnings(true);
+        let outcome = link_wheel_files(
+            LinkMode::Hardlink,
+            &site_packages,
+            &unpacked,
+            &state,
+            &wheel,
+        )?;
+        assert_eq!(outcome.effective_mode, LinkMode::Copy);
+
+        assert!(
+            !uv_warnings::WARNINGS
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|warning| warning.starts_with("Failed to hardlink files"))
+        );
+
+        Ok(())
+    }
+}
@@ -24,6 +24,8 @@ pub struct Installer<'a> {
     metadata: bool,
     /// Preview settings for the installer.
     preview: Preview,
+    /// Whether to suppress the warnings emitted when linking falls back to a slower mode.
+    suppress_fallback_warnings: bool,
 }
 
 impl<'a> Installer<'a> {
@@ -37,6 +39,7 @@ impl<'a> Installer<'a> {
             name: Some("uv".to_string()),
             metadata: true,
             preview,
+            suppress_fallback_warnings: false,
         }
     }
 
@@ -82,6 +85,16 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set whether to suppress the warnings emitted when linking falls back to a slower
+    /// [`LinkMode`], e.g., when hard linking fails and files are copied instead.
+    #[must_use]
+    pub fn with_suppress_fallback_warnings(self, suppress_fallback_warnings: bool) -> Self {
+        Self {
+            suppress_fallback_warnings,
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -93,6 +106,7 @@ impl<'a> Installer<'a> {
             name: installer_name,
             metadata: installer_metadata,
             preview,
+            suppress_fallback_warnings,
         } = self;
 
         if cache.is_some_and(Cache::is_temporary) {
@@ -119,6 +133,7 @@ impl<'a> Installer<'a> {
                 relocatable,
                 installer_metadata,
                 preview,
+                suppress_fallback_warnings,
             );
 
             // This may fail if the main task was cancelled.
@@ -150,6 +165,7 @@ impl<'a> Installer<'a> {
             self.venv.relocatable(),
             self.metadata,
             self.preview,
+            self.suppress_fallback_warnings,
         )
     }
 }
@@ -165,10 +181,12 @@ fn install(
     relocatable: bool,
     installer_metadata: bool,
     preview: Preview,
+    suppress_fallback_warnings: bool,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
     initialize_rayon_once();
-    let state = uv_install_wheel::InstallState::new(preview);
+    let state = uv_install_wheel::InstallState::new(preview)
+        .with_suppress_fallback_warnings(suppress_fallback_warnings);
     wheels.par_iter().try_for_each(|wheel| {
         uv_install_wheel::install_wheel(
             layout,
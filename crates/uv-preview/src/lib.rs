@@ -264,6 +264,7 @@ pub enum PreviewFeature {
     NoDistutilsPatch = 1 << 38,
     IndexHashAlgorithm = 1 << 39,
     LockfileFormatCheck = 1 << 40,
+    Pep740Attestation = 1 << 41,
 }
 
 impl PreviewFeature {
@@ -310,6 +311,7 @@ impl PreviewFeature {
             Self::NoDistutilsPatch => "no-distutils-patch",
             Self::IndexHashAlgorithm => "index-hash-algorithm",
             Self::LockfileFormatCheck => "lockfile-format-check",
+            Self::Pep740Attestation => "pep740-attestation",
         }
     }
 }
@@ -369,6 +371,7 @@ impl FromStr for PreviewFeature {
             "no-distutils-patch" => Self::NoDistutilsPatch,
             "index-hash-algorithm" => Self::IndexHashAlgorithm,
             "lockfile-format-check" => Self::LockfileFormatCheck,
+            "pep740-attestation" => Self::Pep740Attestation,
             _ => return Err(PreviewFeatureParseError),
         })
     }
@@ -0,0 +1,70 @@
+//! Generate [PEP 740](https://peps.python.org/pep-0740/) attestations for distributions.
+
+use std::path::PathBuf;
+
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+use crate::{PublishError, UploadDistribution};
+
+/// The executable used to sign distributions, provided by the `pypi-attestations` package.
+const PYPI_ATTESTATIONS: &str = "pypi-attestations";
+
+/// Sign each distribution that doesn't have a publish attestation yet, and attach the resulting
+/// `<dist>.publish.attestation` file to its upload.
+///
+/// Signing is delegated to `pypi-attestations sign`, which obtains a Sigstore identity (e.g., an
+/// ambient OIDC token in CI, or an interactive browser flow) and writes the attestation next to
+/// the distribution.
+pub async fn generate_attestations(groups: &mut [UploadDistribution]) -> Result<(), PublishError> {
+    let mut executable = None;
+
+    for group in groups {
+        let attestation = publish_attestation_path(group);
+        if group.attestations.contains(&attestation) {
+            debug!(
+                "Using existing attestation for `{}`",
+                group.file.user_display()
+            );
+            continue;
+        }
+
+        let executable = match &executable {
+            Some(executable) => executable,
+            None => executable.insert(
+                which::which(PYPI_ATTESTATIONS).map_err(PublishError::AttestationToolNotFound)?,
+            ),
+        };
+
+        debug!("Generating attestation for `{}`", group.file.user_display());
+        let status = tokio::process::Command::new(executable)
+            .arg("sign")
+            .arg(&group.file)
+            .status()
+            .await
+            .map_err(|err| PublishError::GenerateAttestation(group.file.clone(), err))?;
+        if !status.success() {
+            return Err(PublishError::GenerateAttestationStatus(
+                group.file.clone(),
+                status,
+            ));
+        }
+        if !attestation.is_file() {
+            return Err(PublishError::MissingAttestation(attestation));
+        }
+
+        group.attestations.push(attestation);
+        group.attestations.sort();
+    }
+
+    Ok(())
+}
+
+/// Return the path of the publish attestation for a distribution, e.g.,
+/// `foo-1.2.3.tar.gz.publish.attestation`.
+fn publish_attestation_path(group: &UploadDistribution) -> PathBuf {
+    group
+        .file
+        .with_file_name(format!("{}.publish.attestation", group.raw_filename))
+}
@@ -1,3 +1,5 @@
+mod attestations;
+mod signatures;
 mod trusted_publishing;
 
 use std::collections::BTreeSet;
@@ -35,16 +37,22 @@ use uv_distribution_types::{IndexCapabilities, IndexUrl};
 use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
-use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
+use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError, ResolutionMetadata};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_warnings::warn_user;
 
+pub use crate::attestations::generate_attestations;
+pub use crate::signatures::generate_signatures;
 use crate::trusted_publishing::pypi::PyPIPublishingService;
 use crate::trusted_publishing::pyx::PyxPublishingService;
 use crate::trusted_publishing::{
     TrustedPublishingError, TrustedPublishingService, TrustedPublishingToken,
 };
 
+/// File extensions of legacy distribution formats that PyPI no longer accepts, as built by
+/// `bdist_egg`, `bdist_wininst`, and `bdist_msi`.
+const LEGACY_DISTRIBUTION_EXTENSIONS: &[&str] = &[".egg", ".exe", ".msi"];
+
 #[derive(Error, Debug)]
 pub enum PublishError {
     #[error("The publish path is not a valid glob pattern: `{0}`")]
@@ -100,6 +108,24 @@ pub enum PublishError {
     S3Upload(PathBuf, #[source] Box<PublishSendError>),
     #[error("Failed to finalize upload for `{}`", _0.user_display())]
     Finalize(PathBuf, #[source] Box<PublishSendError>),
+    #[error(
+        "Failed to find `pypi-attestations`, which is required to generate attestations (install it with `uv tool install pypi-attestations`)"
+    )]
+    AttestationToolNotFound(#[source] which::Error),
+    #[error("Failed to generate attestation for `{}`", _0.user_display())]
+    GenerateAttestation(PathBuf, #[source] io::Error),
+    #[error("Failed to generate attestation for `{}`: `pypi-attestations` exited with {}", _0.user_display(), _1)]
+    GenerateAttestationStatus(PathBuf, std::process::ExitStatus),
+    #[error("Expected `pypi-attestations` to write an attestation to: `{}`", _0.user_display())]
+    MissingAttestation(PathBuf),
+    #[error("Failed to find `gpg`, which is required to sign distributions")]
+    SignatureToolNotFound(#[source] which::Error),
+    #[error("Failed to sign `{}`", _0.user_display())]
+    GenerateSignature(PathBuf, #[source] io::Error),
+    #[error("Failed to sign `{}`: `gpg` exited with {}", _0.user_display(), _1)]
+    GenerateSignatureStatus(PathBuf, std::process::ExitStatus),
+    #[error("Expected `gpg` to write a signature to: `{}`", _0.user_display())]
+    MissingSignature(PathBuf),
 }
 
 /// Failure to get the metadata for a specific file.
@@ -121,6 +147,8 @@ pub enum PublishPrepareError {
     Read(String, #[source] io::Error),
     #[error("Invalid PEP 740 attestation (not JSON): `{0}`")]
     InvalidAttestation(PathBuf, #[source] serde_json::Error),
+    #[error("Invalid wheel metadata")]
+    InvalidMetadata(#[source] MetadataError),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -177,6 +205,29 @@ pub struct CheckUrlClient<'a> {
 }
 
 impl PublishSendError {
+    /// Returns `true` if the registry rejected the upload because the file already exists.
+    ///
+    /// Registries don't agree on how to report this case, so, as with `twine upload
+    /// --skip-existing`, we match on both the status code and the error message.
+    fn is_existing_file(&self) -> bool {
+        let Self::Status(status, message) = self else {
+            return false;
+        };
+        match *status {
+            // PyPI and Nexus.
+            StatusCode::BAD_REQUEST => {
+                message.contains("File already exists")
+                    || message.contains("Repository does not allow updating assets")
+                    || message.contains("cannot be updated")
+            }
+            // Artifactory, GitLab, and others.
+            StatusCode::CONFLICT => true,
+            // pypiserver.
+            StatusCode::FORBIDDEN => message.contains("overwrite artifact"),
+            _ => false,
+        }
+    }
+
     /// Extract `code` from the PyPI json error response, if any.
     ///
     /// The error response from PyPI contains crucial context, such as the difference between
@@ -272,7 +323,7 @@ impl PublishSendError {
 }
 
 /// Represents a single "to-be-uploaded" distribution, along with zero
-/// or more attestations that will be uploaded alongside it.
+/// or more attestations and an optional signature that will be uploaded alongside it.
 #[derive(Debug)]
 pub struct UploadDistribution {
     /// The path to the main distribution file to upload.
@@ -283,6 +334,8 @@ pub struct UploadDistribution {
     pub filename: DistFilename,
     /// Zero or more paths to PEP 740 attestations for the distribution.
     pub attestations: Vec<PathBuf>,
+    /// The path to a detached GPG signature for the distribution, if any.
+    pub signature: Option<PathBuf>,
 }
 
 /// Given a list of paths (which may contain globs), unroll them into
@@ -353,6 +406,15 @@ fn group_files(files: Vec<PathBuf>, no_attestations: bool) -> Vec<UploadDistribu
                         but is not a valid distribution filename: `{}`",
                         file.user_display()
                     );
+                } else if let Some(extension) = LEGACY_DISTRIBUTION_EXTENSIONS
+                    .iter()
+                    .find(|extension| filename.ends_with(**extension))
+                {
+                    warn_user!(
+                        "Skipping legacy `{extension}` distribution, \
+                        only wheels and source distributions can be uploaded: `{}`",
+                        file.user_display()
+                    );
                 }
                 continue;
             };
@@ -364,6 +426,7 @@ fn group_files(files: Vec<PathBuf>, no_attestations: bool) -> Vec<UploadDistribu
                     raw_filename: filename,
                     filename: dist_filename,
                     attestations: Vec::new(),
+                    signature: None,
                 },
             );
         }
@@ -510,6 +573,7 @@ pub async fn upload(
     retry_policy: ExponentialBackoff,
     credentials: &Credentials,
     check_url_client: Option<&CheckUrlClient<'_>>,
+    skip_existing: bool,
     download_concurrency: &Semaphore,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
@@ -617,6 +681,13 @@ pub async fn upload(
                 Ok(true)
             }
             Err(err) => {
+                if skip_existing && err.is_existing_file() {
+                    debug!(
+                        "Skipping file that already exists on the registry: `{}`",
+                        group.file.user_display()
+                    );
+                    return Ok(false);
+                }
                 if matches!(
                     err,
                     PublishSendError::Status(..) | PublishSendError::StatusNoBody(..)
@@ -658,6 +729,7 @@ pub async fn validate(
     store: &PyxTokenStore,
     client: &BaseClient,
     credentials: &Credentials,
+    skip_existing: bool,
 ) -> Result<bool, PublishError> {
     if store.is_known_url(registry) {
         debug!("Performing validation request for {registry}");
@@ -712,13 +784,18 @@ pub async fn validate(
         }
 
         // Handle error response.
-        handle_response(&validation_url, response)
-            .await
-            .map_err(|err| {
-                PublishError::Validate(file.to_path_buf(), registry.clone().into(), err.into())
-            })?;
-
-        Ok(true)
+        match handle_response(&validation_url, response).await {
+            Ok(()) => Ok(true),
+            Err(err) if skip_existing && err.is_existing_file() => {
+                debug!("Skipping file that already exists on the registry: {raw_filename}");
+                Ok(false)
+            }
+            Err(err) => Err(PublishError::Validate(
+                file.to_path_buf(),
+                registry.clone().into(),
+                err.into(),
+            )),
+        }
     } else {
         debug!("Skipping validation request for unsupported publish URL: {registry}");
         Ok(true)
@@ -741,6 +818,7 @@ pub async fn upload_two_phase(
     s3_client: &BaseClient,
     retry_policy: ExponentialBackoff,
     credentials: &Credentials,
+    skip_existing: bool,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
     #[derive(Debug, Deserialize)]
@@ -797,10 +875,15 @@ pub async fn upload_two_phase(
         }
         _ => {
             let body = response.text().await.unwrap_or_default();
-            return Err(PublishError::Reserve(
-                group.file.clone(),
-                PublishSendError::Status(status, body).into(),
-            ));
+            let err = PublishSendError::Status(status, body);
+            if skip_existing && err.is_existing_file() {
+                debug!(
+                    "Skipping file that already exists on the registry: {}",
+                    group.raw_filename
+                );
+                return Ok(false);
+            }
+            return Err(PublishError::Reserve(group.file.clone(), err.into()));
         }
     };
 
@@ -932,13 +1015,20 @@ pub async fn upload_two_phase(
         )
     })?;
 
-    handle_response(&finalize_url, response)
-        .await
-        .map_err(|err| PublishError::Finalize(group.file.clone(), err.into()))?;
-
-    debug!("Upload finalized for {}", group.raw_filename);
-
-    Ok(true)
+    match handle_response(&finalize_url, response).await {
+        Ok(()) => {
+            debug!("Upload finalized for {}", group.raw_filename);
+            Ok(true)
+        }
+        Err(err) if skip_existing && err.is_existing_file() => {
+            debug!(
+                "Skipping file that already exists on the registry: {}",
+                group.raw_filename
+            );
+            Ok(false)
+        }
+        Err(err) => Err(PublishError::Finalize(group.file.clone(), err.into())),
+    }
 }
 
 /// Check whether we should skip the upload of a file because it already exists on the index.
@@ -1142,10 +1232,36 @@ async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, Pu
     Ok(Metadata23::parse(&contents)?)
 }
 
+/// Validate the `METADATA` of a wheel with the same parser used during installation.
+///
+/// Unlike the metadata collected for the upload form, which is parsed leniently, this rejects
+/// malformed fields such as invalid `Requires-Dist` entries. Source distributions are not
+/// validated, as their `PKG-INFO` may declare fields as dynamic.
+pub async fn verify_metadata(
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<(), PublishPrepareError> {
+    let DistFilename::WheelFilename(wheel) = filename else {
+        return Ok(());
+    };
+    let reader = BufReader::new(File::open(&file).await?);
+    let contents = read_metadata_async_seek(wheel, reader).await?;
+    ResolutionMetadata::parse_metadata(&contents).map_err(PublishPrepareError::InvalidMetadata)?;
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct FormMetadata(Vec<(&'static str, String)>);
 
 impl FormMetadata {
+    /// The SHA-256 digest of the distribution.
+    pub fn sha256_digest(&self) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(key, _)| *key == "sha256_digest")
+            .map(|(_, value)| value.as_str())
+    }
+
     /// Collect the non-file fields for the multipart request from the package METADATA.
     ///
     /// Reference implementation: <https://github.com/pypi/warehouse/blob/d2c36d992cf9168e0518201d998b2707a3ef1e72/warehouse/forklift/legacy.py#L1376-L1430>
@@ -1352,6 +1468,12 @@ async fn build_upload_request<'a>(
         form = form.text("attestations", attestations_json);
     }
 
+    if let Some(signature_path) = &group.signature {
+        let signature = fs_err::read(signature_path)?;
+        let part = Part::bytes(signature).file_name(format!("{}.asc", group.raw_filename));
+        form = form.part("gpg_signature", part);
+    }
+
     // If we have a username but no password, attach the username to the URL so the authentication
     // middleware can find the matching password.
     let url = if let Some(username) = credentials
@@ -1533,7 +1655,7 @@ mod tests {
 
     use crate::{
         FormMetadata, PublishError, Reporter, UploadDistribution, build_upload_request,
-        group_files, upload,
+        generate_attestations, generate_signatures, group_files, upload, verify_metadata,
     };
     use tokio::sync::Semaphore;
     use uv_errors::{ErrorOptions, Hints, write_error_chain_with_options};
@@ -1556,7 +1678,10 @@ mod tests {
         fn on_hash_complete(&self, _id: usize) {}
     }
 
-    async fn mock_server_upload(mock_server: &MockServer) -> Result<bool, PublishError> {
+    async fn mock_server_upload(
+        mock_server: &MockServer,
+        skip_existing: bool,
+    ) -> Result<bool, PublishError> {
         let raw_filename = "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl";
         let file = PathBuf::from("../../test/links/").join(raw_filename);
         let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
@@ -1566,6 +1691,7 @@ mod tests {
             raw_filename: raw_filename.to_string(),
             filename,
             attestations: vec![],
+            signature: None,
         };
 
         let form_metadata =
@@ -1590,6 +1716,7 @@ mod tests {
             client.retry_policy(),
             &Credentials::basic(Some("ferris".to_string()), Some("F3RR!S".to_string())),
             None,
+            skip_existing,
             &download_concurrency,
             Arc::new(DummyReporter),
         )
@@ -1653,6 +1780,7 @@ mod tests {
                         },
                     ),
                     attestations: [],
+                    signature: None,
                 },
                 UploadDistribution {
                     file: "dist/acme-1.2.3.tar.gz",
@@ -1667,6 +1795,7 @@ mod tests {
                         },
                     ),
                     attestations: [],
+                    signature: None,
                 },
             ]
             "#);
@@ -1721,6 +1850,7 @@ mod tests {
                                 "dist/acme-1.2.3-py3-none-any.whl.frob.attestation",
                                 "dist/acme-1.2.3-py3-none-any.whl.publish.attestation",
                             ],
+                            signature: None,
                         },
                         UploadDistribution {
                             file: "dist/acme-1.2.3.tar.gz",
@@ -1739,6 +1869,7 @@ mod tests {
                                 "dist/acme-1.2.3.tar.gz.frob.attestation",
                                 "dist/acme-1.2.3.tar.gz.publish.attestation",
                             ],
+                            signature: None,
                         },
                     ]
                     "#);
@@ -1792,6 +1923,7 @@ mod tests {
                                 },
                             ),
                             attestations: [],
+                            signature: None,
                         },
                         UploadDistribution {
                             file: "dist/acme-1.2.3.tar.gz",
@@ -1806,6 +1938,7 @@ mod tests {
                                 },
                             ),
                             attestations: [],
+                            signature: None,
                         },
                     ]
                     "#);
@@ -1853,6 +1986,7 @@ mod tests {
                     attestations: [
                         "dist/acme-1.2.3-py3-none-any.whl.build.attestation",
                     ],
+                    signature: None,
                 },
                 UploadDistribution {
                     file: "dist/acme-1.2.3.tar.gz",
@@ -1869,12 +2003,66 @@ mod tests {
                     attestations: [
                         "dist/acme-1.2.3.tar.gz.frob.attestation",
                     ],
+                    signature: None,
                 },
             ]
             "#);
         }
     }
 
+    /// Existing publish attestations are reused rather than generated again.
+    #[tokio::test]
+    async fn generate_attestations_existing() {
+        let raw_filename = "acme-1.2.3.tar.gz";
+        let mut groups = vec![UploadDistribution {
+            file: PathBuf::from("dist").join(raw_filename),
+            raw_filename: raw_filename.to_string(),
+            filename: DistFilename::try_from_normalized_filename(raw_filename).unwrap(),
+            attestations: vec![PathBuf::from("dist/acme-1.2.3.tar.gz.publish.attestation")],
+            signature: None,
+        }];
+
+        generate_attestations(&mut groups).await.unwrap();
+
+        assert_eq!(
+            groups[0].attestations,
+            vec![PathBuf::from("dist/acme-1.2.3.tar.gz.publish.attestation")]
+        );
+    }
+
+    /// Existing signatures are reused rather than overwritten.
+    #[tokio::test]
+    async fn generate_signatures_existing() {
+        let dist = tempfile::tempdir().unwrap();
+        let raw_filename = "acme-1.2.3.tar.gz";
+        let signature = dist.path().join("acme-1.2.3.tar.gz.asc");
+        fs_err::write(dist.path().join(raw_filename), "").unwrap();
+        fs_err::write(&signature, "existing").unwrap();
+
+        let mut groups = vec![UploadDistribution {
+            file: dist.path().join(raw_filename),
+            raw_filename: raw_filename.to_string(),
+            filename: DistFilename::try_from_normalized_filename(raw_filename).unwrap(),
+            attestations: vec![],
+            signature: None,
+        }];
+
+        generate_signatures(&mut groups, None).await.unwrap();
+
+        assert_eq!(groups[0].signature, Some(signature.clone()));
+        assert_eq!(fs_err::read_to_string(&signature).unwrap(), "existing");
+    }
+
+    /// Valid wheel metadata passes verification.
+    #[tokio::test]
+    async fn verify_metadata_wheel() {
+        let raw_filename = "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl";
+        let file = PathBuf::from("../../test/links/").join(raw_filename);
+        let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+
+        verify_metadata(&file, &filename).await.unwrap();
+    }
+
     #[test]
     fn form_metadata_import_names() {
         let filename = DistFilename::try_from_normalized_filename("pkg-1.0.0.tar.gz").unwrap();
@@ -1927,6 +2115,7 @@ mod tests {
                 raw_filename: raw_filename.to_string(),
                 filename,
                 attestations: vec![],
+                signature: None,
             }
         };
 
@@ -2052,6 +2241,7 @@ mod tests {
                 raw_filename: raw_filename.to_string(),
                 filename,
                 attestations: vec![],
+                signature: None,
             }
         };
 
@@ -2219,7 +2409,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        assert!(mock_server_upload(&mock_server).await.unwrap());
+        assert!(mock_server_upload(&mock_server, false).await.unwrap());
     }
 
     #[tokio::test]
@@ -2242,7 +2432,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let err = mock_server_upload(&mock_server).await.unwrap_err();
+        let err = mock_server_upload(&mock_server, false).await.unwrap_err();
 
         let mut capture = String::new();
         write_error_chain_with_options(
@@ -2275,7 +2465,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let err = mock_server_upload(&mock_server).await.unwrap_err();
+        let err = mock_server_upload(&mock_server, false).await.unwrap_err();
 
         let mut capture = String::new();
         write_error_chain_with_options(
@@ -2313,7 +2503,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let err = mock_server_upload(&mock_server).await.unwrap_err();
+        let err = mock_server_upload(&mock_server, false).await.unwrap_err();
 
         let mut capture = String::new();
         write_error_chain_with_options(
@@ -2354,7 +2544,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let err = mock_server_upload(&mock_server).await.unwrap_err();
+        let err = mock_server_upload(&mock_server, false).await.unwrap_err();
 
         let mut capture = String::new();
         write_error_chain_with_options(
@@ -2374,4 +2564,21 @@ mod tests {
         "
         );
     }
+
+    /// With `--skip-existing`, a rejected upload of an existing file is skipped.
+    #[tokio::test]
+    async fn upload_skip_existing() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/final"))
+            .respond_with(ResponseTemplate::new(400).set_body_string(
+                "400 File already exists ('tqdm-4.66.1-py3-none-any.whl', with blake2_256 hash \
+                '0123'). See https://pypi.org/help/#file-name-reuse for more information.",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        assert!(!mock_server_upload(&mock_server, true).await.unwrap());
+        assert!(mock_server_upload(&mock_server, false).await.is_err());
+    }
 }
@@ -0,0 +1,77 @@
+//! Generate detached GPG signatures for distributions.
+
+use std::path::PathBuf;
+
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+use crate::{PublishError, UploadDistribution};
+
+/// The executable used to sign distributions.
+const GPG: &str = "gpg";
+
+/// Sign each distribution that doesn't have a `<dist>.asc` signature yet with GPG, and attach the
+/// signature to its upload.
+///
+/// Signing is delegated to `gpg --detach-sign --armor`, using the default key unless an
+/// `identity` (e.g., a key fingerprint) is provided.
+pub async fn generate_signatures(
+    groups: &mut [UploadDistribution],
+    identity: Option<&str>,
+) -> Result<(), PublishError> {
+    let mut executable = None;
+
+    for group in groups {
+        let signature = signature_path(group);
+        if signature.is_file() {
+            debug!(
+                "Using existing signature for `{}`",
+                group.file.user_display()
+            );
+            group.signature = Some(signature);
+            continue;
+        }
+
+        let executable = match &executable {
+            Some(executable) => executable,
+            None => {
+                executable.insert(which::which(GPG).map_err(PublishError::SignatureToolNotFound)?)
+            }
+        };
+
+        debug!("Generating signature for `{}`", group.file.user_display());
+        let mut command = tokio::process::Command::new(executable);
+        command.arg("--detach-sign").arg("--armor");
+        if let Some(identity) = identity {
+            command.arg("--local-user").arg(identity);
+        }
+        let status = command
+            .arg("--output")
+            .arg(&signature)
+            .arg(&group.file)
+            .status()
+            .await
+            .map_err(|err| PublishError::GenerateSignature(group.file.clone(), err))?;
+        if !status.success() {
+            return Err(PublishError::GenerateSignatureStatus(
+                group.file.clone(),
+                status,
+            ));
+        }
+        if !signature.is_file() {
+            return Err(PublishError::MissingSignature(signature));
+        }
+
+        group.signature = Some(signature);
+    }
+
+    Ok(())
+}
+
+/// Return the path of the detached signature for a distribution, e.g., `foo-1.2.3.tar.gz.asc`.
+fn signature_path(group: &UploadDistribution) -> PathBuf {
+    group
+        .file
+        .with_file_name(format!("{}.asc", group.raw_filename))
+}
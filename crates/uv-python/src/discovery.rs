@@ -2063,6 +2063,28 @@ impl PythonRequest {
         }
     }
 
+    /// Check if this request is at least as specific as `other`, i.e., whether any interpreter
+    /// satisfying this request also satisfies `other`.
+    ///
+    /// For example, `3.11.5` satisfies `3.11`, but `3.11` does not satisfy `3.11.5`.
+    pub fn satisfies(&self, other: &Self) -> bool {
+        match (self, other) {
+            (_, Self::Any) => true,
+            (Self::Version(version), Self::Version(other_version)) => {
+                version.satisfies(other_version)
+            }
+            (
+                Self::ImplementationVersion(implementation, version),
+                Self::ImplementationVersion(other_implementation, other_version),
+            ) => implementation == other_implementation && version.satisfies(other_version),
+            (
+                Self::ImplementationVersion(implementation, _),
+                Self::Implementation(other_implementation),
+            ) => implementation == other_implementation,
+            _ => self == other,
+        }
+    }
+
     /// Check if a given interpreter satisfies the interpreter request.
     pub fn satisfied(&self, interpreter: &Interpreter, cache: &Cache) -> bool {
         /// Returns `true` if the two paths refer to the same interpreter executable.
@@ -2721,6 +2743,28 @@ impl VersionRequest {
         Self::Range(specifiers, variant)
     }
 
+    /// Check if this request is at least as specific as `other`, i.e., whether every version
+    /// allowed by this request is also allowed by `other`.
+    pub fn satisfies(&self, other: &Self) -> bool {
+        match other {
+            Self::Any => return true,
+            Self::Default => return matches!(self, Self::Default),
+            _ => {}
+        }
+
+        if self.variant() != other.variant() {
+            return false;
+        }
+
+        let (Some(specifiers), Some(other_specifiers)) =
+            (self.as_version_specifiers(), other.as_version_specifiers())
+        else {
+            return false;
+        };
+        release_specifiers_to_ranges(specifiers)
+            .subset_of(&release_specifiers_to_ranges(other_specifiers))
+    }
+
     /// Drop any patch or prerelease information from the version request.
     #[must_use]
     pub fn only_minor(self) -> Self {
@@ -4700,4 +4744,41 @@ mod tests {
                 .intersects_requires_python(&requires_python)
         );
     }
+
+    #[test]
+    fn python_request_satisfies() {
+        let satisfies = |request: &str, other: &str| {
+            PythonRequest::parse(request).satisfies(&PythonRequest::parse(other))
+        };
+
+        // More specific versions satisfy less specific ones, but not the reverse.
+        assert!(satisfies("3.11.5", "3.11"));
+        assert!(satisfies("3.11", "3"));
+        assert!(satisfies("3.11", "3.11"));
+        assert!(!satisfies("3.11", "3.11.5"));
+        assert!(!satisfies("3.11", "3.11.0"));
+        assert!(!satisfies("3", "3.0"));
+        assert!(!satisfies("3.12", "3.11"));
+
+        // Versions are compared against ranges.
+        assert!(satisfies("3.11", ">=3.10"));
+        assert!(satisfies(">=3.11,<3.12", "3.11"));
+        assert!(!satisfies("3.11", ">=3.10,<3.11.5"));
+
+        // Variants must match.
+        assert!(satisfies("3.13t", "3.13t"));
+        assert!(!satisfies("3.13t", "3.13"));
+        assert!(!satisfies("3.13", "3.13t"));
+
+        // Implementations must match.
+        assert!(satisfies("pypy@3.10.14", "pypy@3.10"));
+        assert!(satisfies("pypy@3.10", "pypy"));
+        assert!(!satisfies("pypy@3.10", "cpython@3.10"));
+
+        // Everything satisfies `any`, but `any` and `default` only satisfy themselves.
+        assert!(satisfies("3.11", "any"));
+        assert!(satisfies("default", "default"));
+        assert!(!satisfies("any", "3.11"));
+        assert!(!satisfies("default", "3.11"));
+    }
 }
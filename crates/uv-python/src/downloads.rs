@@ -357,6 +357,14 @@ impl PythonDownloadRequest {
         self
     }
 
+    /// Request a download for the given [`Platform`], rather than the current platform.
+    #[must_use]
+    pub fn with_platform(self, platform: &Platform) -> Self {
+        self.with_os(platform.os)
+            .with_arch(platform.arch)
+            .with_libc(platform.libc)
+    }
+
     #[must_use]
     pub fn with_any_arch(mut self) -> Self {
         self.arch = None;
@@ -1182,7 +1190,7 @@ impl ManagedPythonDownload {
         self.key.os()
     }
 
-    pub(crate) fn sha256(&self) -> Option<&Cow<'static, str>> {
+    pub fn sha256(&self) -> Option<&Cow<'static, str>> {
         self.sha256.as_ref()
     }
 
@@ -15,6 +15,34 @@ impl Exclusions {
         Self { reinstall, upgrade }
     }
 
+    /// Merge two [`Exclusions`], excluding any package that either would exclude.
+    ///
+    /// Unlike [`Reinstall::combine`], which lets earlier settings override later ones, this
+    /// takes the union of both sets, with "all packages" dominating any explicit selection.
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        let reinstall = match (self.reinstall, other.reinstall) {
+            (Reinstall::All, _) | (_, Reinstall::All) => Reinstall::All,
+            (Reinstall::None, reinstall) | (reinstall, Reinstall::None) => reinstall,
+            (
+                Reinstall::Packages(mut packages, mut paths),
+                Reinstall::Packages(other_packages, other_paths),
+            ) => {
+                for package in other_packages {
+                    if !packages.contains(&package) {
+                        packages.push(package);
+                    }
+                }
+                paths.extend(other_paths);
+                Reinstall::Packages(packages, paths)
+            }
+        };
+        Self {
+            reinstall,
+            upgrade: self.upgrade.merge(other.upgrade),
+        }
+    }
+
     pub(crate) fn reinstall(&self, package: &PackageName) -> bool {
         self.reinstall.contains_package(package)
     }
@@ -23,3 +51,59 @@ impl Exclusions {
         self.upgrade.contains(package)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_configuration::Upgrade;
+
+    use super::*;
+
+    fn name(name: &str) -> PackageName {
+        PackageName::from_str(name).unwrap()
+    }
+
+    fn upgrade(package: &str) -> UpgradePackages {
+        UpgradePackages::for_non_project(&Upgrade::package(name(package)))
+    }
+
+    fn upgrade_all() -> UpgradePackages {
+        UpgradePackages::for_non_project(&Upgrade::from_args(Some(true), vec![], vec![]).unwrap())
+    }
+
+    #[test]
+    fn merge_packages() {
+        let first = Exclusions::new(Reinstall::package(name("anyio")), upgrade("idna"));
+        let second = Exclusions::new(Reinstall::package(name("sniffio")), upgrade("flask"));
+        let merged = first.merge(second);
+
+        assert!(merged.reinstall(&name("anyio")));
+        assert!(merged.reinstall(&name("sniffio")));
+        assert!(!merged.reinstall(&name("idna")));
+
+        assert!(merged.upgrade(&name("idna")));
+        assert!(merged.upgrade(&name("flask")));
+        assert!(!merged.upgrade(&name("anyio")));
+    }
+
+    #[test]
+    fn merge_all_dominates() {
+        let first = Exclusions::new(Reinstall::package(name("anyio")), upgrade("idna"));
+        let second = Exclusions::new(Reinstall::All, UpgradePackages::default());
+        let merged = first.merge(second);
+
+        assert!(merged.reinstall(&name("anyio")));
+        assert!(merged.reinstall(&name("flask")));
+        assert!(merged.upgrade(&name("idna")));
+        assert!(!merged.upgrade(&name("flask")));
+
+        let first = Exclusions::new(Reinstall::None, UpgradePackages::default());
+        let second = Exclusions::new(Reinstall::None, upgrade_all());
+        let merged = second.merge(first);
+
+        assert!(!merged.reinstall(&name("anyio")));
+        assert!(merged.upgrade(&name("anyio")));
+        assert!(merged.upgrade(&name("flask")));
+    }
+}
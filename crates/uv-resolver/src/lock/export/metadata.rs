@@ -324,6 +324,9 @@ pub(crate) struct MetadataNode {
     /// The latest known version of this package, when requested by the caller.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     latest_version: Option<Version>,
+    /// The license of this package, when requested by the caller.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    license: Option<String>,
     /// Info about building the package
     #[serde(skip_serializing_if = "Option::is_none", default)]
     build_system: Option<MetadataBuildSystem>,
@@ -343,6 +346,7 @@ impl MetadataNode {
             dependency_groups: Vec::new(),
             optional_dependencies: Vec::new(),
             latest_version: None,
+            license: None,
             wheels: Vec::new(),
             build_system: None,
             sdist: None,
@@ -434,6 +438,10 @@ impl MetadataNode {
         self.latest_version = version;
     }
 
+    pub(crate) fn set_license(&mut self, license: Option<String>) {
+        self.license = license;
+    }
+
     pub(crate) fn set_wheels_from_package(
         &mut self,
         workspace_root: &PortablePathBuf,
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::fmt::Formatter;
+use std::fmt::{Formatter, Write};
 use std::path::{Component, Path, PathBuf};
 
 use owo_colors::OwoColorize;
@@ -17,7 +17,7 @@ use uv_redacted::DisplaySafeUrl;
 
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
 use crate::lock::{Package, PackageId, Source};
-use crate::{Installable, LockError};
+use crate::{AnnotationStyle, Installable, LockError};
 
 /// An export of a [`Lock`] that renders in `requirements.txt` format.
 #[derive(Debug)]
@@ -25,6 +25,7 @@ pub struct RequirementsTxtExport<'lock> {
     nodes: Vec<ExportableRequirement<'lock>>,
     hashes: bool,
     editable: Option<EditableMode>,
+    annotation_style: AnnotationStyle,
 }
 
 impl<'lock> RequirementsTxtExport<'lock> {
@@ -34,6 +35,7 @@ impl<'lock> RequirementsTxtExport<'lock> {
         extras: &ExtrasSpecificationWithDefaults,
         dev: &DependencyGroupsWithDefaults,
         annotate: bool,
+        annotation_style: AnnotationStyle,
         editable: Option<EditableMode>,
         hashes: bool,
         install_options: &'lock InstallOptions,
@@ -57,6 +59,7 @@ impl<'lock> RequirementsTxtExport<'lock> {
             nodes,
             hashes,
             editable,
+            annotation_style,
         })
     }
 }
@@ -70,6 +73,7 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
             dependents,
         } in &self.nodes
         {
+            let mut line = String::new();
             match &package.id.source {
                 Source::Registry(_) => {
                     let version = package
@@ -77,7 +81,7 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                         .version
                         .as_ref()
                         .expect("registry package without version");
-                    write!(f, "{}=={}", package.id.name, version)?;
+                    write!(line, "{}=={}", package.id.name, version)?;
                 }
                 Source::Git(url, git) => {
                     // Remove the fragment and query from the URL; they're already present in the
@@ -111,7 +115,7 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                         })
                     };
 
-                    write!(f, "{} @ {}", package.id.name, url)?;
+                    write!(line, "{} @ {}", package.id.name, url)?;
                 }
                 Source::Direct(url, direct) => {
                     let url = DisplaySafeUrl::from(ParsedArchiveUrl {
@@ -120,7 +124,7 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                         ext: DistExtension::Source(SourceDistExtension::TarGz),
                     });
                     write!(
-                        f,
+                        line,
                         "{} @ {}",
                         package.id.name,
                         // TODO(zanieb): We should probably omit passwords here by default, but we
@@ -131,12 +135,12 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                 Source::Path(path) | Source::Directory(path) => {
                     if path.is_absolute() {
                         write!(
-                            f,
+                            line,
                             "{}",
                             Url::from_file_path(path).map_err(|()| std::fmt::Error)?
                         )?;
                     } else {
-                        write!(f, "{}", anchor(path).portable_display())?;
+                        write!(line, "{}", anchor(path).portable_display())?;
                     }
                 }
                 Source::Editable(path) => match self
@@ -145,17 +149,17 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                     .and_then(|editable| editable.for_package(&package.id.name))
                 {
                     None | Some(true) => {
-                        write!(f, "-e {}", anchor(path).portable_display())?;
+                        write!(line, "-e {}", anchor(path).portable_display())?;
                     }
                     Some(false) => {
                         if path.is_absolute() {
                             write!(
-                                f,
+                                line,
                                 "{}",
                                 Url::from_file_path(path).map_err(|()| std::fmt::Error)?
                             )?;
                         } else {
-                            write!(f, "{}", anchor(path).portable_display())?;
+                            write!(line, "{}", anchor(path).portable_display())?;
                         }
                     }
                 },
@@ -165,33 +169,60 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
             }
 
             if let Some(contents) = marker.contents() {
-                write!(f, " ; {contents}")?;
+                write!(line, " ; {contents}")?;
             }
 
+            let mut has_hashes = false;
             if self.hashes {
                 let mut hashes = package.hashes();
                 hashes.sort_unstable();
                 if !hashes.is_empty() {
+                    has_hashes = true;
                     for hash in hashes.iter() {
-                        writeln!(f, " \\")?;
-                        write!(f, "    --hash=")?;
-                        write!(f, "{hash}")?;
+                        writeln!(line, " \\")?;
+                        write!(line, "    --hash=")?;
+                        write!(line, "{hash}")?;
                     }
                 }
             }
 
-            writeln!(f)?;
-
             // Add "via ..." comments for all dependents.
-            match dependents.as_slice() {
-                [] => {}
-                [dependent] => {
-                    writeln!(f, "{}", format!("    # via {}", dependent.id.name).green())?;
-                }
-                _ => {
-                    writeln!(f, "{}", "    # via".green())?;
-                    for &dependent in dependents {
-                        writeln!(f, "{}", format!("    #   {}", dependent.id.name).green())?;
+            match self.annotation_style {
+                AnnotationStyle::Line => match dependents.as_slice() {
+                    [] => {
+                        writeln!(f, "{line}")?;
+                    }
+                    dependents => {
+                        let separator = if has_hashes { "\n    " } else { "  " };
+                        let dependents = dependents
+                            .iter()
+                            .map(|dependent| dependent.id.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        let comment = format!("# via {dependents}").green().to_string();
+                        // Assemble the line with the annotations and remove trailing whitespaces.
+                        for line in format!("{line:24}{separator}{comment}").lines() {
+                            writeln!(f, "{}", line.trim_end())?;
+                        }
+                    }
+                },
+                AnnotationStyle::Split => {
+                    writeln!(f, "{line}")?;
+                    match dependents.as_slice() {
+                        [] => {}
+                        [dependent] => {
+                            writeln!(f, "{}", format!("    # via {}", dependent.id.name).green())?;
+                        }
+                        _ => {
+                            writeln!(f, "{}", "    # via".green())?;
+                            for &dependent in dependents {
+                                writeln!(
+                                    f,
+                                    "{}",
+                                    format!("    #   {}", dependent.id.name).green()
+                                )?;
+                            }
+                        }
                     }
                 }
             }
@@ -3523,6 +3523,11 @@ impl Package {
         self.id.version.as_ref()
     }
 
+    /// Returns `true` if the package is virtual, i.e., it is never installed.
+    pub fn is_virtual(&self) -> bool {
+        matches!(self.id.source, Source::Virtual(_))
+    }
+
     /// Returns the Git SHA of the package, if it is a Git source.
     pub fn git_sha(&self) -> Option<&GitOid> {
         match &self.id.source {
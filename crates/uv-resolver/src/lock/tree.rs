@@ -50,6 +50,8 @@ pub struct TreeDisplay<'env> {
     roots: Vec<NodeIndex>,
     /// The latest known version of each package.
     latest: &'env PackageMap<Version>,
+    /// The license of each package, if known.
+    licenses: &'env PackageMap<String>,
     /// Maximum display depth of the dependency tree.
     depth: usize,
     /// Whether to de-duplicate the displayed dependencies.
@@ -74,6 +76,7 @@ impl<'env> TreeDisplay<'env> {
         lock: &'env Lock,
         markers: Option<&'env ResolverMarkerEnvironment>,
         latest: &'env PackageMap<Version>,
+        licenses: &'env PackageMap<String>,
         depth: usize,
         prune: &[PackageName],
         packages: &[PackageName],
@@ -489,6 +492,7 @@ impl<'env> TreeDisplay<'env> {
             graph,
             roots,
             latest,
+            licenses,
             depth,
             no_dedupe,
             invert,
@@ -500,6 +504,52 @@ impl<'env> TreeDisplay<'env> {
         }
     }
 
+    /// Display only the packages with one of the given licenses.
+    ///
+    /// Licenses are compared case-insensitively. Dependencies between the remaining packages are
+    /// retained, and any remaining package without a remaining dependent becomes a root.
+    #[must_use]
+    pub fn with_licenses_filter(mut self, filter: &[String]) -> Self {
+        if filter.is_empty() {
+            return self;
+        }
+
+        let licenses = self.licenses;
+        self.graph.retain_nodes(|graph, index| {
+            let Node::Package(package_id) = graph[index] else {
+                return false;
+            };
+            licenses.get(package_id).is_some_and(|license| {
+                filter
+                    .iter()
+                    .any(|filter| filter.eq_ignore_ascii_case(license))
+            })
+        });
+
+        let mut roots = self
+            .graph
+            .node_indices()
+            .filter(|index| {
+                self.graph
+                    .edges_directed(*index, Direction::Incoming)
+                    .next()
+                    .is_none()
+            })
+            .collect::<Vec<_>>();
+        roots.sort_by_key(|index| &self.graph[*index]);
+        self.roots = roots;
+
+        self
+    }
+
+    /// Return the packages in the dependency tree.
+    pub fn packages(&self) -> impl Iterator<Item = &'env Package> {
+        self.graph.node_weights().filter_map(|node| match node {
+            Node::Package(package_id) => Some(self.lock.find_by_id(package_id)),
+            Node::Root => None,
+        })
+    }
+
     /// Perform a depth-first traversal of the given package and its dependencies.
     fn visit(
         &'env self,
@@ -564,6 +614,12 @@ impl<'env> TreeDisplay<'env> {
                 }
             }
 
+            // Append the license, if known.
+            if let Some(license) = self.licenses.get(package_id) {
+                line.push(' ');
+                line.push_str(format!("{}", format!("(license: {license})").dimmed()).as_str());
+            }
+
             line
         };
 
@@ -1105,6 +1161,7 @@ impl<'tree, 'env> JsonGraphBuilder<'tree, 'env> {
             let mut node = MetadataNode::from_package_id(&self.workspace_root, package_id, kind);
             if is_package {
                 node.set_latest_version(self.tree.latest.get(package_id).cloned());
+                node.set_license(self.tree.licenses.get(package_id).cloned());
                 node.set_wheels_from_package(&self.workspace_root, package);
             }
             node
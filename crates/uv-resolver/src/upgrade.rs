@@ -83,4 +83,21 @@ impl UpgradePackages {
     pub fn contains(&self, package_name: &PackageName) -> bool {
         self.all || self.packages.contains(package_name)
     }
+
+    /// Merge two [`UpgradePackages`], upgrading any package that either would upgrade.
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        if self.all || other.all {
+            return Self {
+                all: true,
+                packages: FxHashSet::default(),
+            };
+        }
+        let mut packages = self.packages;
+        packages.extend(other.packages);
+        Self {
+            all: false,
+            packages,
+        }
+    }
 }
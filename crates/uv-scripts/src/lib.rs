@@ -124,10 +124,10 @@ impl Pep723ItemRef<'_> {
         match source_strategy {
             NoSources::None => Cow::Borrowed(sources),
             NoSources::All => Cow::Borrowed(&EMPTY),
-            NoSources::Packages(packages) => Cow::Owned(
+            NoSources::Packages(_) => Cow::Owned(
                 sources
                     .iter()
-                    .filter(|(name, _)| !packages.contains(name))
+                    .filter(|(name, _)| !source_strategy.for_package(name))
                     .map(|(name, sources)| (name.clone(), sources.clone()))
                     .collect(),
             ),
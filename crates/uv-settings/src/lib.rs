@@ -5,7 +5,7 @@ use std::str::FromStr;
 use std::time::Duration;
 use tracing::info_span;
 use uv_client::{DEFAULT_CONNECT_TIMEOUT, DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD};
-use uv_configuration::RequiredVersion;
+use uv_configuration::{NoSourcesPackage, RequiredVersion};
 use uv_dirs::{system_config_file, user_config_dir};
 use uv_distribution_types::Origin;
 use uv_flags::EnvironmentFlags;
@@ -690,6 +690,7 @@ pub struct Concurrency {
     pub builds: Option<NonZeroUsize>,
     pub installs: Option<NonZeroUsize>,
     pub cache_reads: Option<NonZeroUsize>,
+    pub cache_clean: Option<NonZeroUsize>,
 }
 
 /// A boolean flag parsed from an environment variable.
@@ -749,6 +750,7 @@ pub struct EnvironmentOptions {
     pub preview: EnvFlag,
     pub isolated: EnvFlag,
     pub no_progress: EnvFlag,
+    pub no_rlimit: EnvFlag,
     pub no_installer_metadata: EnvFlag,
     pub dev: EnvFlag,
     pub no_dev: EnvFlag,
@@ -764,7 +766,7 @@ pub struct EnvironmentOptions {
     pub no_group: Option<Vec<GroupName>>,
     pub no_binary_package: Option<Vec<PackageName>>,
     pub no_build_package: Option<Vec<PackageName>>,
-    pub no_sources_package: Option<Vec<PackageName>>,
+    pub no_sources_package: Option<Vec<NoSourcesPackage>>,
     pub venv_seed: EnvFlag,
     pub venv_clear: EnvFlag,
     pub venv_relocatable: EnvFlag,
@@ -818,6 +820,10 @@ impl EnvironmentOptions {
                     EnvVars::UV_CONCURRENT_CACHE_READS,
                     None,
                 )?,
+                cache_clean: parse_integer_environment_variable(
+                    EnvVars::UV_CACHE_CLEAN_CONCURRENCY,
+                    None,
+                )?,
             },
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: parse_string_environment_variable(
@@ -871,6 +877,7 @@ impl EnvironmentOptions {
             preview: EnvFlag::new(EnvVars::UV_PREVIEW)?,
             isolated: EnvFlag::new(EnvVars::UV_ISOLATED)?,
             no_progress: EnvFlag::new(EnvVars::UV_NO_PROGRESS)?,
+            no_rlimit: EnvFlag::new(EnvVars::UV_NO_RLIMIT)?,
             no_installer_metadata: EnvFlag::new(EnvVars::UV_NO_INSTALLER_METADATA)?,
             dev: EnvFlag::new(EnvVars::UV_DEV)?,
             no_dev: EnvFlag::new(EnvVars::UV_NO_DEV)?,
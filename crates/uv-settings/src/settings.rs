@@ -6,8 +6,9 @@ use serde::{Deserialize, Serialize};
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    BuildIsolation, ExcludeDependency, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    BuildIsolation, ExcludeDependency, IndexStrategy, KeyringProviderType, NoSourcesPackage,
+    PackageNameSpecifier, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, Origin,
@@ -531,7 +532,7 @@ pub struct InstallerOptions {
     no_binary: Option<bool>,
     no_binary_package: Option<Vec<PackageName>>,
     no_sources: Option<bool>,
-    no_sources_package: Option<Vec<PackageName>>,
+    no_sources_package: Option<Vec<NoSourcesPackage>>,
 }
 
 /// Settings relevant to all resolver operations.
@@ -563,7 +564,7 @@ pub struct ResolverOptions {
     pub extra_build_dependencies: Option<ExtraBuildDependencies>,
     pub extra_build_variables: Option<ExtraBuildVariables>,
     pub no_sources: Option<bool>,
-    pub no_sources_package: Option<Vec<PackageName>>,
+    pub no_sources_package: Option<Vec<NoSourcesPackage>>,
 }
 
 /// Shared settings, relevant to all operations that must resolve and install dependencies. The
@@ -592,7 +593,7 @@ pub struct ResolverInstallerOptions {
     pub torch_backend: Option<TorchMode>,
     pub compile_bytecode: Option<bool>,
     pub no_sources: Option<bool>,
-    pub no_sources_package: Option<Vec<PackageName>>,
+    pub no_sources_package: Option<Vec<NoSourcesPackage>>,
     pub upgrade: Option<Upgrade>,
     pub reinstall: Option<Reinstall>,
     pub no_build: Option<bool>,
@@ -1086,6 +1087,9 @@ pub struct ResolverInstallerSchema {
     )]
     pub no_sources: Option<bool>,
     /// Ignore `tool.uv.sources` for the specified packages.
+    ///
+    /// A package may be followed by a list of extras (e.g., `flask[async]`) to only ignore its
+    /// sources when all of those extras are requested.
     #[option(
         default = "[]",
         value_type = "list[str]",
@@ -1093,7 +1097,7 @@ pub struct ResolverInstallerSchema {
             no-sources-package = ["ruff"]
         "#
     )]
-    pub no_sources_package: Option<Vec<PackageName>>,
+    pub no_sources_package: Option<Vec<NoSourcesPackage>>,
     /// Allow package upgrades, ignoring pinned versions in any existing output file.
     #[option(
         default = "false",
@@ -2005,6 +2009,9 @@ pub struct PipOptions {
     )]
     pub no_sources: Option<bool>,
     /// Ignore `tool.uv.sources` for the specified packages.
+    ///
+    /// A package may be followed by a list of extras (e.g., `flask[async]`) to only ignore its
+    /// sources when all of those extras are requested.
     #[option(
         default = "[]",
         value_type = "list[str]",
@@ -2012,7 +2019,7 @@ pub struct PipOptions {
             no-sources-package = ["ruff"]
         "#
     )]
-    pub no_sources_package: Option<Vec<PackageName>>,
+    pub no_sources_package: Option<Vec<NoSourcesPackage>>,
     /// Allow package upgrades, ignoring pinned versions in any existing output file.
     #[option(
         default = "false",
@@ -2226,7 +2233,7 @@ pub struct ToolOptions {
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
-    no_sources_package: Option<Vec<PackageName>>,
+    no_sources_package: Option<Vec<NoSourcesPackage>>,
     no_build: Option<bool>,
     no_build_package: Option<Vec<PackageName>>,
     no_binary: Option<bool>,
@@ -2261,7 +2268,7 @@ pub struct ToolOptionsWire {
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
-    no_sources_package: Option<Vec<PackageName>>,
+    no_sources_package: Option<Vec<NoSourcesPackage>>,
     no_build: Option<bool>,
     no_build_package: Option<Vec<PackageName>>,
     no_binary: Option<bool>,
@@ -2492,7 +2499,7 @@ struct OptionsWire {
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
-    no_sources_package: Option<Vec<PackageName>>,
+    no_sources_package: Option<Vec<NoSourcesPackage>>,
     upgrade: Option<bool>,
     upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     reinstall: Option<bool>,
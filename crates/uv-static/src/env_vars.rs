@@ -426,11 +426,25 @@ impl EnvVars {
     #[attr_added_in("0.11.29")]
     pub const UV_CONCURRENT_CACHE_READS: &'static str = "UV_CONCURRENT_CACHE_READS";
 
+    /// Controls the number of threads used to remove files when clearing the entire cache with
+    /// `uv cache clean`.
+    ///
+    /// Defaults to the available parallelism. Set to `1` to remove files serially.
+    #[attr_added_in("next release")]
+    pub const UV_CACHE_CLEAN_CONCURRENCY: &'static str = "UV_CACHE_CLEAN_CONCURRENCY";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     #[attr_added_in("0.2.28")]
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
 
+    /// Disables adjustment of the open file descriptor limit (`RLIMIT_NOFILE`) on Unix.
+    ///
+    /// When set, uv will not query or raise the limit, avoiding the `getrlimit` and `setrlimit`
+    /// system calls entirely, e.g., in sandboxes that audit or deny them.
+    #[attr_added_in("next release")]
+    pub const UV_NO_RLIMIT: &'static str = "UV_NO_RLIMIT";
+
     /// Specifies the directory where uv stores managed tools.
     #[attr_added_in("0.2.16")]
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
@@ -648,6 +662,11 @@ impl EnvVars {
     #[attr_added_in("0.1.11")]
     pub const UV_COMMIT_DATE: &'static str = "UV_COMMIT_DATE";
 
+    /// Used to set the version of `rustc` used to build uv at build time via `build.rs`.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const UV_RUSTC_VERSION: &'static str = "UV_RUSTC_VERSION";
+
     /// Used to set the uv tag at build time via `build.rs`.
     #[attr_hidden]
     #[attr_added_in("0.1.11")]
@@ -1133,6 +1152,11 @@ impl EnvVars {
     #[attr_added_in("0.1.11")]
     pub const TARGET: &'static str = "TARGET";
 
+    /// Used to determine the `rustc` version at build time via `build.rs`.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const RUSTC: &'static str = "RUSTC";
+
     /// If set, uv will use this value as the log level for its `--verbose` output. Accepts
     /// any filter compatible with the `tracing_subscriber` crate.
     ///
@@ -100,7 +100,7 @@ pub const INSTA_FILTERS: &[(&str, &str)] = &[
     // Operation times
     (r"(\s|\()(\d+m )?(\d+\.)?\d+(ms|s)", "$1[TIME]"),
     // File sizes
-    (r"(\s|\()(\d+\.)?\d+([KM]i)?B", "$1[SIZE]"),
+    (r"(\s|\()(\d+\.)?\d+([KM]i)?B\b", "$1[SIZE]"),
     // Timestamps
     (r"tv_sec: \d+", "tv_sec: [TIME]"),
     (r"tv_nsec: \d+", "tv_nsec: [TIME]"),
@@ -1461,6 +1461,13 @@ impl TestContext {
         command
     }
 
+    pub fn self_uninstall(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("self").arg("uninstall");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv publish` command with options shared across scenarios.
     pub fn publish(&self) -> Command {
         let mut command = self.new_command();
@@ -1532,6 +1539,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python hash` command with options shared across scenarios.
+    pub fn python_hash(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("python").arg("hash");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv python exec` command with options shared across scenarios.
+    pub fn python_exec(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("python").arg("exec");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv run` command with options shared across scenarios.
     pub fn run(&self) -> Command {
         let mut command = self.new_command();
@@ -557,7 +557,7 @@ pub(crate) fn create(
 
     match seed {
         Seed::Enabled => pyvenv_cfg_data.push(("seed".to_string(), "true".to_string())),
-        Seed::Disabled => {}
+        Seed::Disabled | Seed::Excluded => {}
     }
 
     if let Some(prompt) = prompt {
@@ -714,12 +714,20 @@ pub enum Seed {
     /// Do not seed the virtual environment.
     #[default]
     Disabled,
+    /// Do not seed the virtual environment, and require that no seed packages are present.
+    Excluded,
 }
 
 impl Seed {
     /// Determine the [`Seed`] setting based on the command-line arguments.
-    pub fn from_args(seed: bool) -> Self {
-        if seed { Self::Enabled } else { Self::Disabled }
+    pub fn from_args(seed: bool, without_pip: bool) -> Self {
+        if without_pip {
+            Self::Excluded
+        } else if seed {
+            Self::Enabled
+        } else {
+            Self::Disabled
+        }
     }
 }
 
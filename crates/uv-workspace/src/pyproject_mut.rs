@@ -13,7 +13,7 @@ use toml_edit::{
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::{Index, IndexFormat, IndexUrl};
 use uv_fs::{PortablePath, is_same_file_allow_missing};
-use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_normalize::{DEV_DEPENDENCIES, ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
 
@@ -1364,6 +1364,76 @@ impl PyProjectTomlMut {
         types
     }
 
+    /// Returns the names of all dependencies declared for the given dependency type, in order of
+    /// appearance and without duplicates.
+    ///
+    /// For [`DependencyType::Dev`], this includes both `tool.uv.dev-dependencies` and the `dev`
+    /// dependency group.
+    pub fn dependency_names(&self, dependency_type: &DependencyType) -> Vec<PackageName> {
+        let project = self.doc.get("project").and_then(Item::as_table);
+        let group = |group: &GroupName| {
+            self.doc
+                .get("dependency-groups")
+                .and_then(Item::as_table)
+                .and_then(|groups| {
+                    groups.iter().find_map(|(key, value)| {
+                        GroupName::from_str(key)
+                            .is_ok_and(|key| key == *group)
+                            .then_some(value)
+                    })
+                })
+                .and_then(Item::as_array)
+        };
+        let dev_dependencies = || {
+            self.doc
+                .get("tool")
+                .and_then(Item::as_table)
+                .and_then(|tool| tool.get("uv"))
+                .and_then(Item::as_table)
+                .and_then(|uv| uv.get("dev-dependencies"))
+                .and_then(Item::as_array)
+        };
+
+        let arrays: Vec<&Array> = match dependency_type {
+            DependencyType::Production => project
+                .and_then(|project| project.get("dependencies"))
+                .and_then(Item::as_array)
+                .into_iter()
+                .collect(),
+            DependencyType::Optional(extra) => project
+                .and_then(|project| project.get("optional-dependencies"))
+                .and_then(Item::as_table)
+                .and_then(|extras| {
+                    extras.iter().find_map(|(key, value)| {
+                        ExtraName::from_str(key)
+                            .is_ok_and(|key| key == *extra)
+                            .then_some(value)
+                    })
+                })
+                .and_then(Item::as_array)
+                .into_iter()
+                .collect(),
+            DependencyType::Dev => dev_dependencies()
+                .into_iter()
+                .chain(group(&DEV_DEPENDENCIES))
+                .collect(),
+            DependencyType::Group(name) if *name == *DEV_DEPENDENCIES => {
+                dev_dependencies().into_iter().chain(group(name)).collect()
+            }
+            DependencyType::Group(name) => group(name).into_iter().collect(),
+        };
+
+        let mut names = Vec::new();
+        for dependency in arrays.into_iter().flat_map(Array::iter) {
+            if let Some(requirement) = dependency.as_str().and_then(try_parse_requirement)
+                && !names.contains(&requirement.name)
+            {
+                names.push(requirement.name);
+            }
+        }
+        names
+    }
+
     pub fn version(&mut self) -> Result<Version, Error> {
         let version = self
             .doc
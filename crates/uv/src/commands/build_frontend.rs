@@ -27,7 +27,7 @@ use uv_distribution_types::{
     PackageConfigSettings, Requirement, SourceDist,
 };
 use uv_errors::{ErrorOptions, Hint, Hints, write_error_chain_with_options};
-use uv_fs::{Simplified, normalize_path, relative_to};
+use uv_fs::{Simplified, copy_dir_all, normalize_path, relative_to};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -39,7 +39,10 @@ use uv_python::{
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
-use uv_types::{AnyErrorBuild, BuildContext, BuildStack, HashStrategy, SourceTreeEditablePolicy};
+use uv_types::{
+    AnyErrorBuild, BuildContext, BuildStack, HashStrategy, SourceBuildTrait,
+    SourceTreeEditablePolicy,
+};
 use uv_warnings::warn_user;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError};
@@ -100,6 +103,10 @@ pub(crate) enum Error {
     NameMismatch(PackageName, PackageName),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error(
+        "The build backend did not provide metadata through `prepare_metadata_for_build_wheel`, which is required by `--metadata-only`"
+    )]
+    MissingMetadata,
 }
 
 impl Hint for Error {
@@ -144,9 +151,12 @@ pub(crate) async fn build_frontend(
     package: Option<PackageName>,
     all_packages: bool,
     output_dir: Option<PathBuf>,
+    sdist_dir: Option<PathBuf>,
+    wheel_dir: Option<PathBuf>,
     sdist: bool,
     wheel: bool,
     list: bool,
+    metadata_only: bool,
     build_logs: bool,
     gitignore: bool,
     force_pep517: bool,
@@ -173,9 +183,12 @@ pub(crate) async fn build_frontend(
         package.as_ref(),
         all_packages,
         output_dir.as_deref(),
+        sdist_dir.as_deref(),
+        wheel_dir.as_deref(),
         sdist,
         wheel,
         list,
+        metadata_only,
         build_logs,
         gitignore,
         force_pep517,
@@ -222,9 +235,12 @@ async fn build_impl(
     package: Option<&PackageName>,
     all_packages: bool,
     output_dir: Option<&Path>,
+    sdist_dir: Option<&Path>,
+    wheel_dir: Option<&Path>,
     sdist: bool,
     wheel: bool,
     list: bool,
+    metadata_only: bool,
     build_logs: bool,
     gitignore: bool,
     force_pep517: bool,
@@ -415,6 +431,8 @@ async fn build_impl(
         let future = build_package(
             source.clone(),
             output_dir,
+            sdist_dir,
+            wheel_dir,
             python_request,
             install_mirrors.clone(),
             config_discovery,
@@ -445,6 +463,7 @@ async fn build_impl(
             sdist,
             wheel,
             list,
+            metadata_only,
             dependency_metadata,
             *link_mode,
             config_setting,
@@ -491,6 +510,8 @@ async fn build_impl(
 async fn build_package(
     source: AnnotatedSource<'_>,
     output_dir: Option<&Path>,
+    sdist_dir: Option<&Path>,
+    wheel_dir: Option<&Path>,
     python_request: Option<&str>,
     install_mirrors: PythonInstallMirrors,
     config_discovery: ConfigDiscovery,
@@ -521,6 +542,7 @@ async fn build_package(
     sdist: bool,
     wheel: bool,
     list: bool,
+    metadata_only: bool,
     dependency_metadata: &DependencyMetadata,
     link_mode: LinkMode,
     config_setting: &ConfigSettings,
@@ -540,9 +562,24 @@ async fn build_package(
         }
     };
 
-    // Clear the output directory if requested
-    if clear && output_dir.exists() {
-        fs_err::remove_dir_all(&*output_dir)?;
+    // Source distributions and wheels may be written to separate directories.
+    let sdist_dir = match sdist_dir {
+        Some(sdist_dir) => Cow::Owned(std::path::absolute(sdist_dir)?),
+        None => output_dir.clone(),
+    };
+    let wheel_dir = match wheel_dir {
+        Some(wheel_dir) => Cow::Owned(std::path::absolute(wheel_dir)?),
+        None => output_dir,
+    };
+
+    // Clear the output directories if requested
+    if clear {
+        if sdist_dir.exists() {
+            fs_err::remove_dir_all(&*sdist_dir)?;
+        }
+        if wheel_dir != sdist_dir && wheel_dir.exists() {
+            fs_err::remove_dir_all(&*wheel_dir)?;
+        }
     }
 
     // (1) Explicit request from user
@@ -675,10 +712,15 @@ async fn build_package(
         preview,
     );
 
-    prepare_output_directory(&output_dir, gitignore).await?;
+    prepare_output_directory(&sdist_dir, gitignore).await?;
+    if wheel_dir != sdist_dir {
+        prepare_output_directory(&wheel_dir, gitignore).await?;
+    }
 
     // Determine the build plan.
-    let plan = BuildPlan::determine(&source, sdist, wheel).map_err(Error::BuildPlan)?;
+    // Preparing the metadata follows the same path as building a wheel.
+    let plan =
+        BuildPlan::determine(&source, sdist, wheel || metadata_only).map_err(Error::BuildPlan)?;
 
     // Check if the build backend is matching uv version that allows calling in the uv build backend
     // directly.
@@ -735,7 +777,7 @@ async fn build_package(
             if list {
                 let sdist_list = build_sdist(
                     source.path(),
-                    &output_dir,
+                    &sdist_dir,
                     build_action,
                     &source,
                     printer,
@@ -752,7 +794,7 @@ async fn build_package(
             }
             let sdist_build = build_sdist(
                 source.path(),
-                &output_dir,
+                &sdist_dir,
                 build_action.force_build(),
                 &source,
                 printer,
@@ -768,7 +810,7 @@ async fn build_package(
             build_results.push(sdist_build.clone());
 
             // Extract the source distribution into a temporary directory.
-            let path = output_dir.join(sdist_build.raw_filename());
+            let path = sdist_dir.join(sdist_build.raw_filename());
             let reader = fs_err::tokio::File::open(&path).await?;
             let ext = SourceDistExtension::from_path(path.as_path())
                 .map_err(|err| Error::InvalidSourceDistExt(path.user_display().to_string(), err))?;
@@ -784,7 +826,7 @@ async fn build_package(
 
             let wheel_build = build_wheel(
                 &extracted,
-                &output_dir,
+                &wheel_dir,
                 build_action,
                 &source,
                 printer,
@@ -795,7 +837,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
-                Some(sdist_build.normalized_filename()),
+                sdist_build.normalized_filename(),
             )
             .await?;
             build_results.push(wheel_build);
@@ -803,7 +845,7 @@ async fn build_package(
         BuildPlan::Sdist => {
             let sdist_build = build_sdist(
                 source.path(),
-                &output_dir,
+                &sdist_dir,
                 build_action,
                 &source,
                 printer,
@@ -818,10 +860,28 @@ async fn build_package(
             .await?;
             build_results.push(sdist_build);
         }
+        BuildPlan::Wheel if metadata_only => {
+            let metadata_build = build_metadata(
+                source.path(),
+                &wheel_dir,
+                build_action,
+                &source,
+                printer,
+                "wheel metadata",
+                &build_dispatch,
+                sources,
+                dist,
+                subdirectory,
+                version_id,
+                build_output,
+            )
+            .await?;
+            build_results.push(metadata_build);
+        }
         BuildPlan::Wheel => {
             let wheel_build = build_wheel(
                 source.path(),
-                &output_dir,
+                &wheel_dir,
                 build_action,
                 &source,
                 printer,
@@ -840,7 +900,7 @@ async fn build_package(
         BuildPlan::SdistAndWheel => {
             let sdist_build = build_sdist(
                 source.path(),
-                &output_dir,
+                &sdist_dir,
                 build_action,
                 &source,
                 printer,
@@ -856,7 +916,7 @@ async fn build_package(
 
             let wheel_build = build_wheel(
                 source.path(),
-                &output_dir,
+                &wheel_dir,
                 build_action,
                 &source,
                 printer,
@@ -867,7 +927,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
-                Some(sdist_build.normalized_filename()),
+                sdist_build.normalized_filename(),
             )
             .await?;
             build_results.push(sdist_build);
@@ -879,7 +939,7 @@ async fn build_package(
             let ext = SourceDistExtension::from_path(source.path()).map_err(|err| {
                 Error::InvalidSourceDistExt(source.path().user_display().to_string(), err)
             })?;
-            let temp_dir = tempfile::tempdir_in(&output_dir)?;
+            let temp_dir = tempfile::tempdir_in(&*wheel_dir)?;
             uv_extract::stream::archive(source.path().display(), reader, ext, temp_dir.path())
                 .await?;
 
@@ -898,22 +958,40 @@ async fn build_package(
                 Err(err) => return Err(err.into()),
             };
 
-            let wheel_build = build_wheel(
-                &extracted,
-                &output_dir,
-                build_action,
-                &source,
-                printer,
-                "wheel from source distribution",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-                source_dist.as_ref(),
-            )
-            .await?;
+            let wheel_build = if metadata_only {
+                build_metadata(
+                    &extracted,
+                    &wheel_dir,
+                    build_action,
+                    &source,
+                    printer,
+                    "wheel metadata from source distribution",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                )
+                .await?
+            } else {
+                build_wheel(
+                    &extracted,
+                    &wheel_dir,
+                    build_action,
+                    &source,
+                    printer,
+                    "wheel from source distribution",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                    source_dist.as_ref(),
+                )
+                .await?
+            };
             build_results.push(wheel_build);
         }
     }
@@ -1157,8 +1235,7 @@ async fn build_wheel(
             }
         }
     };
-    if let Some(expected) = source_dist {
-        let actual = build_message.normalized_filename();
+    if let (Some(expected), Some(actual)) = (source_dist, build_message.normalized_filename()) {
         if expected.name() != actual.name() {
             return Err(Error::NameMismatch(
                 expected.name().clone(),
@@ -1175,6 +1252,98 @@ async fn build_wheel(
     Ok(build_message)
 }
 
+/// Prepare the metadata of a wheel without building it, either through PEP 517 or through a
+/// direct build.
+#[instrument(skip_all)]
+async fn build_metadata(
+    source_tree: &Path,
+    output_dir: &Path,
+    action: BuildAction,
+    source: &AnnotatedSource<'_>,
+    printer: Printer,
+    build_kind_message: &str,
+    // Below is only used with PEP 517 builds
+    build_dispatch: &BuildDispatch<'_>,
+    sources: NoSources,
+    dist: Option<&SourceDist>,
+    subdirectory: Option<&Path>,
+    version_id: Option<&str>,
+    build_output: BuildOutput,
+) -> Result<BuildMessage, Error> {
+    let dist_info_dir = match action {
+        // `--list` conflicts with `--metadata-only`.
+        BuildAction::List | BuildAction::DirectBuild => {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "{}Preparing {} (uv build backend)...",
+                    source.message_prefix(),
+                    build_kind_message
+                )
+                .bold()
+            )?;
+            let source_tree = source_tree.to_path_buf();
+            let output_dir_ = output_dir.to_path_buf();
+            tokio::task::spawn_blocking(move || {
+                uv_build_backend::metadata(&source_tree, &output_dir_, uv_version::version())
+            })
+            .await??
+        }
+        BuildAction::Pep517 => {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "{}Preparing {}...",
+                    source.message_prefix(),
+                    build_kind_message
+                )
+                .bold()
+            )?;
+            let mut builder = build_dispatch
+                .setup_build(
+                    source_tree,
+                    subdirectory,
+                    source.path(),
+                    None,
+                    version_id,
+                    dist,
+                    &sources,
+                    BuildKind::Wheel,
+                    build_output,
+                    BuildStack::default(),
+                )
+                .await
+                .map_err(|err| Error::BuildDispatch(err.into()))?;
+            let metadata_directory = builder
+                .metadata()
+                .await
+                .map_err(Error::BuildDispatch)?
+                .ok_or(Error::MissingMetadata)?;
+
+            // The metadata directory lives in the build environment; copy it to the output
+            // directory, replacing any stale copy.
+            let dist_info_dir = metadata_directory
+                .file_name()
+                .ok_or(Error::MissingMetadata)?
+                .to_string_lossy()
+                .to_string();
+            let target = output_dir.join(&dist_info_dir);
+            if target.exists() {
+                fs_err::remove_dir_all(&target)?;
+            }
+            copy_dir_all(&metadata_directory, &target)?;
+            dist_info_dir
+        }
+    };
+
+    Ok(BuildMessage::Metadata {
+        dist_info_dir,
+        output_dir: output_dir.to_path_buf(),
+    })
+}
+
 /// Create the output directory and add a `.gitignore`.
 async fn prepare_output_directory(output_dir: &Path, gitignore: bool) -> Result<(), Error> {
     // Create the output directory.
@@ -1290,6 +1459,13 @@ enum BuildMessage {
         /// The location of the built distribution.
         output_dir: PathBuf,
     },
+    /// The metadata directory of a wheel, prepared without building the wheel.
+    Metadata {
+        /// The name of the `.dist-info` directory.
+        dist_info_dir: String,
+        /// The location of the `.dist-info` directory.
+        output_dir: PathBuf,
+    },
     /// Show the list of files that would be included in a distribution.
     List {
         /// The normalized name of the build distribution.
@@ -1304,17 +1480,18 @@ enum BuildMessage {
 }
 
 impl BuildMessage {
-    /// The normalized filename of the wheel or source distribution.
-    fn normalized_filename(&self) -> &DistFilename {
+    /// The normalized filename of the wheel or source distribution, if a distribution was built.
+    fn normalized_filename(&self) -> Option<&DistFilename> {
         match self {
             Self::Build {
                 normalized_filename: name,
                 ..
-            } => name,
+            } => Some(name),
+            Self::Metadata { .. } => None,
             Self::List {
                 normalized_filename: name,
                 ..
-            } => name,
+            } => Some(name),
         }
     }
 
@@ -1324,6 +1501,10 @@ impl BuildMessage {
             Self::Build {
                 raw_filename: name, ..
             } => name,
+            Self::Metadata {
+                dist_info_dir: name,
+                ..
+            } => name,
             Self::List {
                 raw_filename: name, ..
             } => name,
@@ -1343,6 +1524,16 @@ impl BuildMessage {
                     output_dir.join(raw_filename).user_display().bold().cyan()
                 )?;
             }
+            Self::Metadata {
+                dist_info_dir,
+                output_dir,
+            } => {
+                writeln!(
+                    printer.stderr(),
+                    "Successfully prepared {}",
+                    output_dir.join(dist_info_dir).user_display().bold().cyan()
+                )?;
+            }
             Self::List {
                 raw_filename,
                 file_list,
@@ -1,24 +1,66 @@
 use std::fmt::Write;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use uv_cache::{Cache, Removal};
-use uv_fs::Simplified;
+use uv_cache::Cache;
+use uv_cli::CleanPackage;
+use uv_fs::{CWD, Simplified};
 use uv_normalize::PackageName;
+use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement};
+use uv_warnings::warn_user;
 
 use crate::commands::reporters::{CleaningDirectoryReporter, CleaningPackageReporter};
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
-/// Clear the cache, removing all entries or those linked to specific packages.
+/// Clear the cache, removing all entries, those linked to specific packages, all entries except
+/// those linked to specific packages, or only build artifacts.
 pub(crate) async fn cache_clean(
-    packages: &[PackageName],
+    packages: &[CleanPackage],
+    packages_from: &[PathBuf],
+    keep_wheels: bool,
+    exclude: &[PackageName],
+    show_inodes: bool,
     force: bool,
+    concurrency: NonZeroUsize,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
+    // Read the package names from any requirements files.
+    let mut packages = packages.to_vec();
+    for path in packages_from {
+        let requirements_txt = RequirementsTxt::parse(path, &*CWD).await?;
+        for entry in requirements_txt
+            .requirements
+            .into_iter()
+            .chain(requirements_txt.editables)
+        {
+            match entry.requirement {
+                RequirementsTxtRequirement::Named(requirement) => {
+                    packages.push(CleanPackage {
+                        name: requirement.name,
+                        version: None,
+                    });
+                }
+                RequirementsTxtRequirement::Unnamed(requirement) => {
+                    if path == Path::new("-") {
+                        warn_user!("Ignoring unnamed requirement `{requirement}` from stdin");
+                    } else {
+                        warn_user!(
+                            "Ignoring unnamed requirement `{requirement}` in `{}`",
+                            path.user_display()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -43,7 +85,41 @@ pub(crate) async fn cache_clean(
         }
     };
 
-    let summary = if packages.is_empty() {
+    let summary = if keep_wheels {
+        writeln!(
+            printer.stderr(),
+            "Removing build artifacts from cache at: {}",
+            cache.root().user_display().cyan()
+        )?;
+
+        let (buckets, summary) = cache.remove_build_artifacts().with_context(|| {
+            format!(
+                "Failed to remove build artifacts from cache at: {}",
+                cache.root().user_display()
+            )
+        })?;
+
+        if !buckets.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "Pruned build artifacts: {}",
+                buckets.iter().map(ToString::to_string).join(", ")
+            )?;
+        }
+
+        summary
+    } else if !exclude.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Clearing cache at: {} (excluding: {})",
+            cache.root().user_display().cyan(),
+            exclude.iter().map(|name| name.cyan()).join(", ")
+        )?;
+
+        cache
+            .clear_except(exclude)
+            .with_context(|| format!("Failed to clear cache at: {}", cache.root().user_display()))?
+    } else if packages.is_empty() && packages_from.is_empty() {
         writeln!(
             printer.stderr(),
             "Clearing cache at: {}",
@@ -55,17 +131,18 @@ pub(crate) async fn cache_clean(
 
         let root = cache.root().to_path_buf();
         cache
-            .clear(Box::new(reporter))
+            .clear(Box::new(reporter), concurrency)
             .with_context(|| format!("Failed to clear cache at: {}", root.user_display()))?
     } else {
         let reporter = CleaningPackageReporter::new(printer, Some(packages.len()));
-        let mut summary = Removal::default();
 
-        for package in packages {
-            let removed = cache.remove(package)?;
-            summary += removed;
-            reporter.on_clean(package.as_str(), &summary);
-        }
+        let requests = packages
+            .iter()
+            .map(|package| (package.name.clone(), package.version.clone()))
+            .collect::<Vec<_>>();
+        let summary = cache.remove_packages(&requests, concurrency, |index, summary| {
+            reporter.on_clean(&packages[index].to_string(), summary);
+        })?;
         reporter.on_complete();
 
         summary
@@ -103,5 +180,13 @@ pub(crate) async fn cache_clean(
 
     writeln!(printer.stderr())?;
 
+    // If requested, write the total number of inodes freed.
+    if show_inodes {
+        match summary.num_files + summary.num_dirs {
+            1 => writeln!(printer.stderr(), "Freed 1 inode")?,
+            num_inodes => writeln!(printer.stderr(), "Freed {num_inodes} inodes")?,
+        }
+    }
+
     Ok(ExitStatus::Success)
 }
@@ -10,8 +10,8 @@ use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user;
 
 /// Display the total size of the cache.
-pub(crate) fn cache_size(
-    cache: &Cache,
+pub(crate) async fn cache_size(
+    cache: Cache,
     human_readable: bool,
     printer: Printer,
     preview: Preview,
@@ -32,6 +32,18 @@ pub(crate) fn cache_size(
         return Ok(ExitStatus::Success);
     }
 
+    // Block cache removal operations from interfering, while allowing concurrent readers.
+    let cache = match cache.with_shared_lock_no_wait() {
+        Ok(cache) => cache,
+        Err(cache) => {
+            writeln!(
+                printer.stderr(),
+                "Cache is currently being modified, waiting for other uv processes to finish"
+            )?;
+            cache.with_shared_lock().await?
+        }
+    };
+
     let disk_usage = DiskUsage::new(vec![cache.root().to_path_buf()]);
 
     let total_bytes = disk_usage.count_ignoring_errors();
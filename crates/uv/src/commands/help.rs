@@ -1,19 +1,14 @@
-use std::ffi::OsString;
-use std::path::PathBuf;
-use std::str::FromStr;
-use std::{fmt::Display, fmt::Write};
+use std::fmt::Write;
 
 use anstream::{ColorChoice, stream::IsTerminal};
 use anyhow::{Result, anyhow};
 use clap::CommandFactory;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use which::which;
 
 use super::ExitStatus;
-use crate::printer::Printer;
+use crate::printer::{Pager, Printer};
 use uv_cli::Cli;
-use uv_static::EnvVars;
 
 // hidden subcommands to show in the help command
 const SHOW_HIDDEN_COMMANDS: &[&str] = &["generate-shell-completion"];
@@ -41,8 +36,16 @@ pub(crate) fn help(query: &[String], printer: Printer, no_pager: bool) -> Result
             nearest
                 .get_subcommands()
                 .filter(|cmd| !cmd.is_hide_set())
-                .map(clap::Command::get_name)
-                .filter(|name| *name != "help")
+                .filter(|cmd| cmd.get_name() != "help")
+                .map(|cmd| {
+                    // Include any visible aliases, e.g., `list (ls)`.
+                    let aliases = cmd.get_visible_aliases().join(", ");
+                    if aliases.is_empty() {
+                        cmd.get_name().to_string()
+                    } else {
+                        format!("{} ({aliases})", cmd.get_name())
+                    }
+                })
                 .join("\n    "),
         )
     })?;
@@ -220,25 +223,50 @@ fn reformat_env_annotations(help: &str) -> String {
     result
 }
 
-/// Extract an inline `[env: VAR=]` annotation from a line.
+/// Extract the inline `[env: VAR=]` annotations from a line.
+///
+/// Both comma-separated lists (e.g., `[env: VAR1=, VAR2=]`) and repeated annotations are
+/// supported, and are combined into a single annotation.
 ///
-/// Returns the annotation and the line with the annotation removed, or `None` if no
+/// Returns the annotation and the line with the annotations removed, or `None` if no
 /// annotation is found.
 fn extract_env_annotation(line: &str) -> Option<(String, String)> {
-    // Look for the pattern: " [env: SOMETHING=]"
-    let start = line.find(" [env: ")?;
-    let rest = &line[start + " [env: ".len()..];
-    let end_offset = rest.find("=]")?;
-
-    // Validate that the environment variable name contains only uppercase letters and underscores.
-    let env_name = &rest[..end_offset];
-    if !env_name.chars().all(|c| c.is_ascii_uppercase() || c == '_') {
-        return None;
+    let mut env_names = Vec::new();
+    let mut new_line = String::new();
+    let mut rest = line;
+
+    // Look for the pattern: " [env: SOMETHING=]" or " [env: SOMETHING=, OTHER=]"
+    while let Some(start) = rest.find(" [env: ") {
+        let body_start = start + " [env: ".len();
+        let Some(end_offset) = rest[body_start..].find(']') else {
+            break;
+        };
+        let annotation_end = body_start + end_offset + "]".len();
+
+        // Validate that each environment variable name contains only uppercase letters and
+        // underscores.
+        let names = rest[body_start..body_start + end_offset]
+            .split(", ")
+            .map(|entry| {
+                entry.strip_suffix('=').filter(|name| {
+                    !name.is_empty() && name.chars().all(|c| c.is_ascii_uppercase() || c == '_')
+                })
+            })
+            .collect::<Option<Vec<_>>>();
+
+        if let Some(names) = names {
+            env_names.extend(names);
+            new_line.push_str(&rest[..start]);
+        } else {
+            new_line.push_str(&rest[..annotation_end]);
+        }
+        rest = &rest[annotation_end..];
     }
+    new_line.push_str(rest);
 
-    let annotation_end = start + " [env: ".len() + end_offset + "=]".len();
-    let annotation = line[start + " ".len()..annotation_end].to_string();
-    let new_line = format!("{}{}", &line[..start], &line[annotation_end..]);
+    if env_names.is_empty() {
+        return None;
+    }
 
     // Only extract if there's actual text remaining (not just whitespace).
     // If the line is just the annotation (clap-generated), leave it alone.
@@ -246,6 +274,7 @@ fn extract_env_annotation(line: &str) -> Option<(String, String)> {
         return None;
     }
 
+    let annotation = format!("[env: {}=]", env_names.join("=, "));
     Some((annotation, new_line))
 }
 
@@ -260,148 +289,89 @@ fn find_command<'a>(
         return Ok(cmd);
     };
 
-    let subcommand = cmd.find_subcommand(next).ok_or((query, cmd))?;
+    // Match the subcommand by name or by any of its aliases, including hidden aliases, e.g.,
+    // `uv help tool ls`.
+    let subcommand = cmd
+        .get_subcommands()
+        .find(|subcommand| {
+            subcommand.get_name() == next || subcommand.get_all_aliases().any(|alias| alias == next)
+        })
+        .ok_or((query, cmd))?;
     find_command(&query[1..], subcommand)
 }
 
-#[derive(Debug)]
-enum PagerKind {
-    Less,
-    More,
-    Other(String),
-}
-
-#[derive(Debug)]
-struct Pager {
-    kind: PagerKind,
-    args: Vec<String>,
-    path: Option<PathBuf>,
-}
-
-impl PagerKind {
-    fn default_args(&self) -> Vec<String> {
-        match self {
-            Self::Less => vec!["-R".to_string()],
-            Self::More => vec![],
-            Self::Other(_) => vec![],
-        }
-    }
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl Display for PagerKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Less => write!(f, "less"),
-            Self::More => write!(f, "more"),
-            Self::Other(name) => write!(f, "{name}"),
-        }
+    #[test]
+    fn extract_env_annotation_single() {
+        let (annotation, line) =
+            extract_env_annotation("          Disable network access [env: UV_OFFLINE=]").unwrap();
+        assert_eq!(annotation, "[env: UV_OFFLINE=]");
+        assert_eq!(line, "          Disable network access");
     }
-}
-
-impl FromStr for Pager {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split_ascii_whitespace();
 
-        // Empty string
-        let Some(first) = split.next() else {
-            return Err(());
-        };
-
-        match first {
-            "less" => Ok(Self {
-                kind: PagerKind::Less,
-                args: split.map(str::to_string).collect(),
-                path: None,
-            }),
-            "more" => Ok(Self {
-                kind: PagerKind::More,
-                args: split.map(str::to_string).collect(),
-                path: None,
-            }),
-            _ => Ok(Self {
-                kind: PagerKind::Other(first.to_string()),
-                args: split.map(str::to_string).collect(),
-                path: None,
-            }),
-        }
-    }
-}
-
-impl Pager {
-    /// Display `contents` using the pager.
-    fn spawn(self, heading: String, contents: impl Display) -> Result<()> {
-        use std::io::Write;
-
-        let command = self
-            .path
-            .as_ref()
-            .map(|path| path.as_os_str().to_os_string())
-            .unwrap_or(OsString::from(self.kind.to_string()));
-
-        let args = if self.args.is_empty() {
-            self.kind.default_args()
-        } else {
-            self.args
-        };
-
-        let mut child = std::process::Command::new(command)
-            .args(args)
-            .stdin(std::process::Stdio::piped())
-            .spawn()?;
-
-        let mut stdin = child
-            .stdin
-            .take()
-            .ok_or_else(|| anyhow!("Failed to take child process stdin"))?;
-
-        let contents = contents.to_string();
-        let writer = std::thread::spawn(move || {
-            let _ = write!(stdin, "{heading}\n\n");
-            let _ = stdin.write_all(contents.as_bytes());
-        });
-
-        drop(child.wait());
-        drop(writer.join());
+    #[test]
+    fn extract_env_annotation_multiple() {
+        // A comma-separated list within a single annotation.
+        let (annotation, line) = extract_env_annotation(
+            "          Use native TLS [env: UV_NATIVE_TLS=, UV_SYSTEM_CERTS=]",
+        )
+        .unwrap();
+        assert_eq!(annotation, "[env: UV_NATIVE_TLS=, UV_SYSTEM_CERTS=]");
+        assert_eq!(line, "          Use native TLS");
 
-        Ok(())
+        // Repeated annotations.
+        let (annotation, line) = extract_env_annotation(
+            "          Use native TLS [env: UV_NATIVE_TLS=] [env: UV_SYSTEM_CERTS=]",
+        )
+        .unwrap();
+        assert_eq!(annotation, "[env: UV_NATIVE_TLS=, UV_SYSTEM_CERTS=]");
+        assert_eq!(line, "          Use native TLS");
     }
 
-    /// Get a pager to use and its path, if available.
-    ///
-    /// Supports the `PAGER` environment variable, otherwise checks for `less` and `more` in the
-    /// search path.
-    fn try_from_env() -> Option<Self> {
-        if let Some(pager) = std::env::var_os(EnvVars::PAGER) {
-            if !pager.is_empty() {
-                return Self::from_str(&pager.to_string_lossy()).ok();
-            }
-        }
-
-        if let Ok(less) = which("less") {
-            Some(Self {
-                kind: PagerKind::Less,
-                args: vec![],
-                path: Some(less),
-            })
-        } else if let Ok(more) = which("more") {
-            Some(Self {
-                kind: PagerKind::More,
-                args: vec![],
-                path: Some(more),
-            })
-        } else {
+    #[test]
+    fn extract_env_annotation_ignored() {
+        // Annotations with values are left inline.
+        assert_eq!(
+            extract_env_annotation(
+                r#"          Disable automatic downloads [env: "UV_PYTHON_DOWNLOADS=never"]"#
+            ),
             None
-        }
+        );
+
+        // Annotations without a description are left alone.
+        assert_eq!(extract_env_annotation("          [env: UV_OFFLINE=]"), None);
     }
 
-    fn supports_colors(&self) -> bool {
-        match self.kind {
-            // The `-R` flag is required for color support. We will provide it by default.
-            PagerKind::Less => self.args.is_empty() || self.args.iter().any(|arg| arg == "-R"),
-            PagerKind::More => false,
-            PagerKind::Other(_) => false,
-        }
+    #[test]
+    fn reformat_env_annotations_multiple() {
+        let help = [
+            "Options:",
+            "      --native-tls",
+            "          Whether to load TLS certificates from the platform's native certificate store [env: UV_NATIVE_TLS=, UV_SYSTEM_CERTS=]",
+            "",
+            "      --offline",
+            "          Disable network access [env: UV_OFFLINE=] [env: UV_NO_NETWORK=]",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            reformat_env_annotations(&help),
+            [
+                "Options:",
+                "      --native-tls",
+                "          Whether to load TLS certificates from the platform's native certificate store.",
+                "",
+                "          [env: UV_NATIVE_TLS=, UV_SYSTEM_CERTS=]",
+                "",
+                "      --offline",
+                "          Disable network access.",
+                "",
+                "          [env: UV_OFFLINE=, UV_NO_NETWORK=]",
+            ]
+            .join("\n")
+        );
     }
 }
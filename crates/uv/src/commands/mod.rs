@@ -46,8 +46,10 @@ pub(crate) use project::upgrade::upgrade;
 pub(crate) use project::version::{project_version, self_version};
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
+pub(crate) use python::exec::exec as python_exec;
 pub(crate) use python::find::find as python_find;
 pub(crate) use python::find::find_script as python_find_script;
+pub(crate) use python::hash::hash as python_hash;
 pub(crate) use python::install::install as python_install;
 pub(crate) use python::install::{PythonUpgrade, PythonUpgradeSource};
 pub(crate) use python::list::list as python_list;
@@ -55,6 +57,8 @@ pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
 #[cfg(feature = "self-update")]
+pub(crate) use self_uninstall::self_uninstall;
+#[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
@@ -96,6 +100,8 @@ mod pylock;
 mod python;
 pub(crate) mod reporters;
 #[cfg(feature = "self-update")]
+mod self_uninstall;
+#[cfg(feature = "self-update")]
 mod self_update;
 mod tool;
 mod venv;
@@ -1121,6 +1121,8 @@ async fn execute_plan(
             .with_link_mode(link_mode)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
+            // With `--quiet`, don't warn when falling back to a slower link mode.
+            .with_suppress_fallback_warnings(matches!(printer, Printer::Quiet | Printer::Silent))
             .with_reporter(Arc::new(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
             ))
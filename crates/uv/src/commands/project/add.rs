@@ -42,7 +42,7 @@ use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_settings::{MalwareCheckSettings, PythonInstallMirrors};
 use uv_static::is_known_standard_library_package;
 use uv_types::{BuildIsolation, HashStrategy, SourceTreeEditablePolicy};
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::pyproject::{
     DependencyType, PyProjectToml, Source, SourceError, Sources, ToolUvSources,
 };
@@ -714,6 +714,17 @@ pub(crate) async fn add(
     // If `--frozen`, exit early. There's no reason to lock and sync, since we don't need a `uv.lock`
     // to exist at all.
     if frozen.is_some() {
+        // If a lockfile exists, it no longer reflects the `pyproject.toml`.
+        if modified && let AddTarget::Project(..) = target {
+            let lock_path = LockTarget::from(&target).lock_path();
+            if lock_path.is_file() {
+                warn_user!(
+                    "The lockfile at `{}` was not updated and may be out of date; run `{}` to update it",
+                    lock_path.user_display().cyan(),
+                    "uv lock".green()
+                );
+            }
+        }
         return Ok(ExitStatus::Success);
     }
 
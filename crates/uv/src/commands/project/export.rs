@@ -19,7 +19,7 @@ use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
 use uv_python::{ConfigDiscovery, PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::{AnnotationStyle, PylockToml, RequirementsTxtExport, cyclonedx_json};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user;
@@ -73,6 +73,7 @@ pub(crate) async fn export(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     include_annotations: bool,
+    annotation_style: AnnotationStyle,
     include_header: bool,
     include_index_url: bool,
     include_find_links: bool,
@@ -375,6 +376,7 @@ pub(crate) async fn export(
                 &extras,
                 &groups,
                 include_annotations,
+                annotation_style,
                 editable,
                 hashes,
                 &install_options,
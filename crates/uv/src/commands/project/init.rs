@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Write;
 use std::iter;
 use std::path::{Path, PathBuf};
@@ -5,12 +6,13 @@ use std::process::Stdio;
 use std::str::FromStr;
 
 use anyhow::{Context, Result, anyhow, bail};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
-use toml_edit::{InlineTable, Value};
+use toml_edit::{Array, DocumentMut, InlineTable, Value};
 use tracing::{debug, trace, warn};
 
 use uv_cache::Cache;
-use uv_cli::AuthorFrom;
+use uv_cli::{AuthorFrom, ReadmeFormat};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
     DependencyGroupsWithDefaults, ProjectBuildBackend, VersionControlError, VersionControlSystem,
@@ -39,6 +41,10 @@ use crate::commands::project::{find_requires_python, init_script_python_requirem
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
 
+use license::License;
+
+mod license;
+
 /// Add one or more packages to the project requirements.
 #[expect(clippy::single_match_else, clippy::fn_params_excessive_bools)]
 pub(crate) async fn init(
@@ -48,11 +54,15 @@ pub(crate) async fn init(
     package: bool,
     init_kind: InitKind,
     bare: bool,
+    template: Option<PathBuf>,
     description: Option<String>,
     no_description: bool,
     vcs: Option<VersionControlSystem>,
     build_backend: Option<ProjectBuildBackend>,
     no_readme: bool,
+    readme_format: Option<ReadmeFormat>,
+    license: Option<String>,
+    private: bool,
     author_from: Option<AuthorFrom>,
     pin_python: bool,
     python: Option<String>,
@@ -83,6 +93,7 @@ pub(crate) async fn init(
                 printer,
                 no_workspace,
                 no_readme,
+                readme_format,
                 author_from,
                 pin_python,
                 package,
@@ -97,6 +108,20 @@ pub(crate) async fn init(
             )?;
         }
         InitKind::Project(project_kind) => {
+            let readme = (!no_readme).then(|| readme_format.unwrap_or_default());
+
+            // Validate the license before creating any files.
+            let license = license
+                .map(|id| {
+                    License::from_id(&id).ok_or_else(|| {
+                        anyhow!(
+                            "Unsupported license `{id}`; expected one of: {}",
+                            License::ids().join(", ")
+                        )
+                    })
+                })
+                .transpose()?;
+
             // Default to the current directory if a path was not provided.
             let path = match explicit_path {
                 None => project_dir.to_path_buf(),
@@ -147,11 +172,14 @@ pub(crate) async fn init(
                 package,
                 project_kind,
                 bare,
+                template,
                 description,
                 no_description,
                 vcs,
                 build_backend,
-                no_readme,
+                readme,
+                license,
+                private,
                 author_from,
                 pin_python,
                 python,
@@ -166,9 +194,11 @@ pub(crate) async fn init(
             ))
             .await?;
 
-            // Create the `README.md` if it does not already exist.
-            if !no_readme && !bare {
-                let readme = path.join("README.md");
+            // Create the README if it does not already exist.
+            if let Some(readme) = readme
+                && !bare
+            {
+                let readme = path.join(readme.filename());
                 if !readme.exists() {
                     fs_err::write(readme, String::new())?;
                 }
@@ -210,6 +240,7 @@ async fn init_script(
     printer: Printer,
     no_workspace: bool,
     no_readme: bool,
+    readme_format: Option<ReadmeFormat>,
     author_from: Option<AuthorFrom>,
     pin_python: bool,
     package: bool,
@@ -221,6 +252,9 @@ async fn init_script(
     if no_readme {
         warn_user_once!("`--no-readme` is a no-op for Python scripts, which are standalone");
     }
+    if readme_format.is_some() {
+        warn_user_once!("`--readme-format` is a no-op for Python scripts, which are standalone");
+    }
     if author_from.is_some() {
         warn_user_once!("`--author-from` is a no-op for Python scripts, which are standalone");
     }
@@ -287,11 +321,14 @@ async fn init_project(
     package: bool,
     project_kind: InitProjectKind,
     bare: bool,
+    template: Option<PathBuf>,
     description: Option<String>,
     no_description: bool,
     vcs: Option<VersionControlSystem>,
     build_backend: Option<ProjectBuildBackend>,
-    no_readme: bool,
+    readme: Option<ReadmeFormat>,
+    license: Option<License>,
+    private: bool,
     author_from: Option<AuthorFrom>,
     pin_python: bool,
     python: Option<String>,
@@ -405,19 +442,56 @@ async fn init_project(
     )
     .await?;
 
-    project_kind.init(
-        name,
-        path,
-        &requires_python,
-        description.as_deref(),
-        no_description,
-        bare,
-        vcs,
-        build_backend,
-        author_from,
-        no_readme,
-        package,
-    )?;
+    if let Some(template) = template {
+        init_from_template(
+            &template,
+            name,
+            path,
+            &requires_python,
+            description.as_deref(),
+            no_description,
+            vcs,
+        )?;
+    } else {
+        project_kind.init(
+            name,
+            path,
+            &requires_python,
+            description.as_deref(),
+            no_description,
+            bare,
+            vcs,
+            build_backend,
+            author_from,
+            readme,
+            package,
+        )?;
+    }
+
+    if private {
+        mark_private(path)?;
+    }
+
+    // Create the `LICENSE` if it does not already exist.
+    if let Some(license) = license {
+        let license_file = path.join("LICENSE");
+        if license_file.try_exists()? {
+            debug!(
+                "Skipping existing license file: `{}`",
+                license_file.user_display()
+            );
+        } else {
+            let author = get_author_info(path, author_from.unwrap_or_default());
+            let holders = author.as_ref().map(|author| match author {
+                Author::Name(name) | Author::NameEmail { name, .. } => name.as_str(),
+                Author::Email(email) => email.as_str(),
+            });
+            let year = jiff::Zoned::now().year();
+            debug!("Writing `{}` license to `LICENSE`", license.id());
+            fs_err::write(license_file, license.render(year, holders))?;
+            set_license(path, license)?;
+        }
+    }
 
     if let Some(workspace) = workspace {
         if workspace.excludes(path)? {
@@ -753,7 +827,6 @@ pub(crate) enum InitProjectKind {
 impl InitProjectKind {
     /// Initialize this project kind at the target path.
     // TODO(konsti): Remove when stabilizing packaged-init.
-    #[expect(clippy::fn_params_excessive_bools)]
     fn init_old(
         self,
         name: &PackageName,
@@ -765,7 +838,7 @@ impl InitProjectKind {
         vcs: Option<VersionControlSystem>,
         build_backend: Option<ProjectBuildBackend>,
         author_from: Option<AuthorFrom>,
-        no_readme: bool,
+        readme: Option<ReadmeFormat>,
         package: bool,
     ) -> Result<()> {
         match self {
@@ -779,7 +852,7 @@ impl InitProjectKind {
                 vcs,
                 build_backend,
                 author_from,
-                no_readme,
+                readme,
                 package,
             ),
             Self::LibraryOld => Self::init_library_old(
@@ -792,7 +865,7 @@ impl InitProjectKind {
                 vcs,
                 build_backend,
                 author_from,
-                no_readme,
+                readme,
                 package,
             ),
             _ => unreachable!(),
@@ -801,7 +874,6 @@ impl InitProjectKind {
 
     /// Initialize a Python application at the target path.
     // TODO(konsti): Remove when stabilizing packaged-init.
-    #[expect(clippy::fn_params_excessive_bools)]
     fn init_application_old(
         name: &PackageName,
         path: &Path,
@@ -812,7 +884,7 @@ impl InitProjectKind {
         vcs: Option<VersionControlSystem>,
         build_backend: Option<ProjectBuildBackend>,
         author_from: Option<AuthorFrom>,
-        no_readme: bool,
+        readme: Option<ReadmeFormat>,
         package: bool,
     ) -> Result<()> {
         fs_err::create_dir_all(path)?;
@@ -838,7 +910,7 @@ impl InitProjectKind {
             author.as_ref(),
             description,
             no_description,
-            no_readme || bare,
+            readme.filter(|_| !bare),
         );
 
         // Include additional project configuration for packaged applications
@@ -885,7 +957,6 @@ impl InitProjectKind {
 
     /// Initialize a library project at the target path.
     // TODO(konsti): Remove when stabilizing packaged-init.
-    #[expect(clippy::fn_params_excessive_bools)]
     fn init_library_old(
         name: &PackageName,
         path: &Path,
@@ -896,7 +967,7 @@ impl InitProjectKind {
         vcs: Option<VersionControlSystem>,
         build_backend: Option<ProjectBuildBackend>,
         author_from: Option<AuthorFrom>,
-        no_readme: bool,
+        readme: Option<ReadmeFormat>,
         package: bool,
     ) -> Result<()> {
         if !package {
@@ -918,7 +989,7 @@ impl InitProjectKind {
             author.as_ref(),
             description,
             no_description,
-            no_readme || bare,
+            readme.filter(|_| !bare),
         );
 
         // Always include a build system if the project is packaged.
@@ -938,7 +1009,6 @@ impl InitProjectKind {
     }
 
     /// Initialize this project kind at the target path.
-    #[expect(clippy::fn_params_excessive_bools)]
     fn init(
         self,
         name: &PackageName,
@@ -950,7 +1020,7 @@ impl InitProjectKind {
         vcs: Option<VersionControlSystem>,
         build_backend: Option<ProjectBuildBackend>,
         author_from: Option<AuthorFrom>,
-        no_readme: bool,
+        readme: Option<ReadmeFormat>,
         package: bool,
     ) -> Result<()> {
         // TODO(konsti): Remove when stabilizing.
@@ -965,7 +1035,7 @@ impl InitProjectKind {
                 vcs,
                 build_backend,
                 author_from,
-                no_readme,
+                readme,
                 package,
             );
         }
@@ -993,7 +1063,7 @@ impl InitProjectKind {
             author.as_ref(),
             description,
             no_description,
-            no_readme || bare,
+            readme.filter(|_| !bare),
         );
 
         match self {
@@ -1054,6 +1124,226 @@ impl InitProjectKind {
     }
 }
 
+/// The classifier that PyPI rejects, to prevent accidental uploads of private projects.
+const PRIVATE_CLASSIFIER: &str = "Private :: Do Not Upload";
+
+/// Add the [`PRIVATE_CLASSIFIER`] to the `pyproject.toml` at the target path.
+fn mark_private(path: &Path) -> Result<()> {
+    let pyproject_toml = path.join("pyproject.toml");
+    let mut document = fs_err::read_to_string(&pyproject_toml)?.parse::<DocumentMut>()?;
+    let Some(project) = document
+        .get_mut("project")
+        .and_then(|project| project.as_table_like_mut())
+    else {
+        bail!(
+            "Failed to mark the project as private: missing `[project]` table in `{}`",
+            pyproject_toml.user_display()
+        );
+    };
+
+    if project.get("classifiers").is_none() {
+        project.insert("classifiers", toml_edit::value(Array::new()));
+        if let Some(mut key) = project.key_mut("classifiers") {
+            key.leaf_decor_mut().set_prefix(format!(
+                "# The `{PRIVATE_CLASSIFIER}` classifier prevents uploads to PyPI.\n"
+            ));
+        }
+    }
+    let Some(classifiers) = project
+        .get_mut("classifiers")
+        .and_then(|classifiers| classifiers.as_array_mut())
+    else {
+        bail!(
+            "Failed to mark the project as private: `project.classifiers` is not an array in `{}`",
+            pyproject_toml.user_display()
+        );
+    };
+    if !classifiers
+        .iter()
+        .any(|classifier| classifier.as_str() == Some(PRIVATE_CLASSIFIER))
+    {
+        classifiers.push(PRIVATE_CLASSIFIER);
+    }
+
+    fs_err::write(pyproject_toml, document.to_string())?;
+    Ok(())
+}
+
+/// Set `project.license` to the SPDX identifier of the [`License`] in the `pyproject.toml` at the
+/// target path, unless the project already declares a license.
+fn set_license(path: &Path, license: License) -> Result<()> {
+    let pyproject_toml = path.join("pyproject.toml");
+    let mut document = fs_err::read_to_string(&pyproject_toml)?.parse::<DocumentMut>()?;
+    let Some(project) = document
+        .get_mut("project")
+        .and_then(|project| project.as_table_like_mut())
+    else {
+        bail!(
+            "Failed to set the project license: missing `[project]` table in `{}`",
+            pyproject_toml.user_display()
+        );
+    };
+
+    if project.contains_key("license") {
+        debug!(
+            "Skipping existing license in `{}`",
+            pyproject_toml.user_display()
+        );
+        return Ok(());
+    }
+    project.insert("license", toml_edit::value(license.id()));
+
+    fs_err::write(pyproject_toml, document.to_string())?;
+    Ok(())
+}
+
+/// The name of the manifest file that marks a directory as a project template.
+const TEMPLATE_MANIFEST: &str = "uv-template.toml";
+
+/// The `uv-template.toml` manifest of a project template.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct TemplateManifest {
+    /// Default values for additional placeholders used by the template.
+    #[serde(default)]
+    variables: BTreeMap<String, String>,
+}
+
+/// Initialize a project from a template directory at the target path.
+///
+/// Every file in the template, except for the manifest, is copied to the target path, with
+/// `{{ variable }}` placeholders in path components and UTF-8 file contents replaced. Existing
+/// files are left untouched.
+fn init_from_template(
+    template: &Path,
+    name: &PackageName,
+    path: &Path,
+    requires_python: &RequiresPython,
+    description: Option<&str>,
+    no_description: bool,
+    vcs: Option<VersionControlSystem>,
+) -> Result<()> {
+    let manifest_path = template.join(TEMPLATE_MANIFEST);
+    if !manifest_path.is_file() {
+        bail!(
+            "`{}` is not a project template (missing `{TEMPLATE_MANIFEST}`)",
+            template.user_display()
+        );
+    }
+    if !template.join("pyproject.toml").is_file() {
+        bail!(
+            "Project template `{}` does not contain a `pyproject.toml`",
+            template.user_display()
+        );
+    }
+    let manifest: TemplateManifest = toml::from_str(&fs_err::read_to_string(&manifest_path)?)
+        .with_context(|| format!("Failed to parse `{}`", manifest_path.user_display()))?;
+
+    // The project-specific variables take precedence over the defaults from the manifest.
+    let mut variables = manifest.variables;
+    variables.insert("name".to_string(), name.to_string());
+    variables.insert("module".to_string(), name.as_dist_info_name().to_string());
+    variables.insert(
+        "description".to_string(),
+        if no_description {
+            String::new()
+        } else {
+            description
+                .unwrap_or("Add your description here")
+                .to_string()
+        },
+    );
+    variables.insert(
+        "requires_python".to_string(),
+        requires_python.specifiers().to_string(),
+    );
+
+    // Render all files up front, to avoid leaving a partially initialized project behind.
+    let mut directories = Vec::new();
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(template)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git")
+    {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(template)?;
+        if relative == Path::new(TEMPLATE_MANIFEST) {
+            continue;
+        }
+        let target = render_template_path(relative, &variables);
+        if entry.file_type().is_dir() {
+            directories.push(target);
+            continue;
+        }
+        let contents = fs_err::read(entry.path())?;
+        let contents = match String::from_utf8(contents) {
+            Ok(contents) => render_template(&contents, &variables).into_bytes(),
+            // Copy binary files verbatim.
+            Err(err) => err.into_bytes(),
+        };
+        files.push((target, contents));
+    }
+
+    fs_err::create_dir_all(path)?;
+
+    // Initialize the version control system first so that Git configuration can properly
+    // read conditional includes that depend on the repository path.
+    init_vcs(path, vcs)?;
+
+    for directory in directories {
+        fs_err::create_dir_all(path.join(directory))?;
+    }
+    for (relative, contents) in files {
+        let target = path.join(relative);
+        if target.try_exists()? {
+            debug!(
+                "Skipping existing file from template: `{}`",
+                target.user_display()
+            );
+            continue;
+        }
+        fs_err::write(&target, contents)?;
+    }
+
+    Ok(())
+}
+
+/// Replace the `{{ variable }}` placeholders in the contents of a template file.
+///
+/// Placeholders that don't refer to a known variable (e.g., `${{ secrets.TOKEN }}` in a GitHub
+/// Actions workflow) are left untouched.
+fn render_template(contents: &str, variables: &BTreeMap<String, String>) -> String {
+    let mut rendered = String::with_capacity(contents.len());
+    let mut rest = contents;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        let end = start + end + "}}".len();
+        let variable = rest[start + "{{".len()..end - "}}".len()].trim();
+        rendered.push_str(&rest[..start]);
+        match variables.get(variable) {
+            Some(value) => rendered.push_str(value),
+            None => rendered.push_str(&rest[start..end]),
+        }
+        rest = &rest[end..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Replace the `{{ variable }}` placeholders in each component of a path in a template.
+fn render_template_path(path: &Path, variables: &BTreeMap<String, String>) -> PathBuf {
+    path.components()
+        .map(|component| match component.as_os_str().to_str() {
+            Some(component) => PathBuf::from(render_template(component, variables)),
+            None => PathBuf::from(component.as_os_str()),
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 enum Author {
     Name(String),
@@ -1089,7 +1379,7 @@ fn pyproject_project(
     author: Option<&Author>,
     description: Option<&str>,
     no_description: bool,
-    no_readme: bool,
+    readme: Option<ReadmeFormat>,
 ) -> String {
     indoc::formatdoc! {r#"
         [project]
@@ -1098,7 +1388,9 @@ fn pyproject_project(
         requires-python = "{requires_python}"
         dependencies = []
     "#,
-        readme = if no_readme { "" } else { "\nreadme = \"README.md\"" },
+        readme = readme
+            .map(|readme| format!("\nreadme = \"{}\"", readme.filename()))
+            .unwrap_or_default(),
         description = if no_description {
             String::new()
         } else {
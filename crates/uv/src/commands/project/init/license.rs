@@ -0,0 +1,100 @@
+//! The license texts that `uv init --license` can write to a new project.
+
+/// The placeholder for the copyright year and holders.
+const COPYRIGHT_NOTICE: &str = "<year> <copyright holders>";
+
+/// The bundled licenses, keyed by SPDX identifier.
+///
+/// The texts are taken from the SPDX license list. Licenses that start with a copyright notice use
+/// the `<year> <copyright holders>` placeholders.
+const LICENSES: &[(&str, &str)] = &[
+    ("0BSD", include_str!("licenses/0BSD.txt")),
+    ("AGPL-3.0-only", include_str!("licenses/AGPL-3.0.txt")),
+    ("AGPL-3.0-or-later", include_str!("licenses/AGPL-3.0.txt")),
+    ("Apache-2.0", include_str!("licenses/Apache-2.0.txt")),
+    ("Artistic-2.0", include_str!("licenses/Artistic-2.0.txt")),
+    ("BSD-2-Clause", include_str!("licenses/BSD-2-Clause.txt")),
+    ("BSD-3-Clause", include_str!("licenses/BSD-3-Clause.txt")),
+    ("BSL-1.0", include_str!("licenses/BSL-1.0.txt")),
+    ("CC0-1.0", include_str!("licenses/CC0-1.0.txt")),
+    ("EPL-2.0", include_str!("licenses/EPL-2.0.txt")),
+    ("EUPL-1.2", include_str!("licenses/EUPL-1.2.txt")),
+    ("GPL-2.0-only", include_str!("licenses/GPL-2.0.txt")),
+    ("GPL-2.0-or-later", include_str!("licenses/GPL-2.0.txt")),
+    ("GPL-3.0-only", include_str!("licenses/GPL-3.0.txt")),
+    ("GPL-3.0-or-later", include_str!("licenses/GPL-3.0.txt")),
+    ("ISC", include_str!("licenses/ISC.txt")),
+    ("LGPL-2.1-only", include_str!("licenses/LGPL-2.1.txt")),
+    ("LGPL-2.1-or-later", include_str!("licenses/LGPL-2.1.txt")),
+    ("LGPL-3.0-only", include_str!("licenses/LGPL-3.0.txt")),
+    ("LGPL-3.0-or-later", include_str!("licenses/LGPL-3.0.txt")),
+    ("MIT", include_str!("licenses/MIT.txt")),
+    ("MIT-0", include_str!("licenses/MIT-0.txt")),
+    ("MPL-2.0", include_str!("licenses/MPL-2.0.txt")),
+    ("Unlicense", include_str!("licenses/Unlicense.txt")),
+    ("Zlib", include_str!("licenses/Zlib.txt")),
+];
+
+/// A bundled license.
+#[derive(Debug, Copy, Clone)]
+pub(super) struct License {
+    /// The SPDX identifier of the license.
+    id: &'static str,
+    /// The license text, with placeholders for the copyright notice.
+    text: &'static str,
+}
+
+impl License {
+    /// Find the bundled license with the given SPDX identifier, ignoring case.
+    pub(super) fn from_id(id: &str) -> Option<Self> {
+        LICENSES
+            .iter()
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(id))
+            .map(|&(id, text)| Self { id, text })
+    }
+
+    /// The SPDX identifiers of all bundled licenses.
+    pub(super) fn ids() -> impl Iterator<Item = &'static str> {
+        LICENSES.iter().map(|(id, _)| *id)
+    }
+
+    /// The SPDX identifier of the license.
+    pub(super) fn id(self) -> &'static str {
+        self.id
+    }
+
+    /// Render the license text, filling in the copyright year and, if known, the holders.
+    pub(super) fn render(self, year: i16, holders: Option<&str>) -> String {
+        let holders = holders.unwrap_or("<copyright holders>");
+        self.text
+            .replacen(COPYRIGHT_NOTICE, &format!("{year} {holders}"), 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_id() {
+        assert_eq!(License::from_id("mit").unwrap().id(), "MIT");
+        assert_eq!(License::from_id("Apache-2.0").unwrap().id(), "Apache-2.0");
+        assert!(License::from_id("GPL-3.0+").is_none());
+    }
+
+    #[test]
+    fn render() {
+        let license = License::from_id("MIT").unwrap();
+        let text = license.render(2026, Some("Jane Doe"));
+        assert!(text.starts_with("MIT License\n\nCopyright (c) 2026 Jane Doe\n"));
+
+        // Without known holders, the placeholder is kept.
+        let text = license.render(2026, None);
+        assert!(text.starts_with("MIT License\n\nCopyright (c) 2026 <copyright holders>\n"));
+
+        // The instructions in the appendix of the GPL are left untouched.
+        let license = License::from_id("GPL-3.0-or-later").unwrap();
+        let text = license.render(2026, Some("Jane Doe"));
+        assert!(text.contains("Copyright (C) <year>  <name of author>"));
+    }
+}
@@ -422,7 +422,7 @@ impl<'lock> LockTarget<'lock> {
 
                 let mut lowered = Vec::new();
                 for requirement in requirements {
-                    if sources.for_package(&requirement.name) {
+                    if sources.for_package_with_extras(&requirement.name, &requirement.extras) {
                         lowered.push(Requirement::from(requirement));
                         continue;
                     }
@@ -46,6 +46,8 @@ pub(crate) async fn remove(
     active: Option<bool>,
     no_sync: bool,
     packages: Vec<PackageName>,
+    all: bool,
+    keep: Vec<PackageName>,
     dependency_type: DependencyType,
     package: Option<PackageName>,
     python: Option<String>,
@@ -121,6 +123,22 @@ pub(crate) async fn remove(
         ),
     }?;
 
+    // If `--all` was provided, remove every dependency of the given type, except those to keep.
+    let packages = if all {
+        let packages = toml
+            .dependency_names(&dependency_type)
+            .into_iter()
+            .filter(|package| !keep.contains(package))
+            .collect::<Vec<_>>();
+        if packages.is_empty() {
+            writeln!(printer.stderr(), "No dependencies to remove")?;
+            return Ok(ExitStatus::Success);
+        }
+        packages
+    } else {
+        packages
+    };
+
     for package in packages {
         match dependency_type {
             DependencyType::Production => {
@@ -4,15 +4,19 @@ use std::path::Path;
 use anstream::print;
 use anyhow::{Error, Result};
 use futures::StreamExt;
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::TreeFormat;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, DependencyGroups, TargetTriple};
-use uv_distribution_types::IndexCapabilities;
+use uv_distribution_types::{IndexCapabilities, InstalledDist};
+use uv_installer::SitePackages;
 use uv_normalize::DefaultGroups;
 use uv_normalize::PackageName;
 use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::Metadata23;
 use uv_python::{ConfigDiscovery, PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
 use uv_resolver::{PackageMap, TreeDisplay, TreeJsonTarget};
 use uv_scripts::Pep723Script;
@@ -45,6 +49,8 @@ pub(crate) async fn tree(
     frozen: Option<FrozenSource>,
     universal: bool,
     format: TreeFormat,
+    licenses: bool,
+    licenses_filter: Vec<String>,
     depth: u8,
     prune: Vec<PackageName>,
     package: Vec<PackageName>,
@@ -299,11 +305,57 @@ pub(crate) async fn tree(
         PackageMap::default()
     };
 
+    // If necessary, read the license of each package from the project environment.
+    let (licenses, uninstalled) = if licenses {
+        let environment = match target {
+            LockTarget::Workspace(workspace) => {
+                ProjectInterpreter::discover_existing(workspace, None, cache)?
+            }
+            LockTarget::Script(script) => {
+                ScriptInterpreter::discover_existing(script.into(), None, cache)
+            }
+        };
+        if let Some(environment) = environment {
+            let site_packages = SitePackages::from_environment(&environment)?;
+            let mut licenses = PackageMap::default();
+            let mut uninstalled = FxHashSet::default();
+            for package in lock.packages() {
+                // Virtual packages are never installed.
+                if package.is_virtual() {
+                    continue;
+                }
+                let Some(version) = package.version() else {
+                    continue;
+                };
+                let Some(dist) = site_packages
+                    .get_packages(package.name())
+                    .into_iter()
+                    .find(|dist| dist.version() == version)
+                else {
+                    uninstalled.insert((package.name(), version));
+                    continue;
+                };
+                if let Some(license) = read_license(dist) {
+                    licenses.insert(package.clone(), license);
+                }
+            }
+            (licenses, Some(uninstalled))
+        } else {
+            warn_user!(
+                "No project environment found; run `uv sync` to install packages before displaying their licenses"
+            );
+            (PackageMap::default(), None)
+        }
+    } else {
+        (PackageMap::default(), None)
+    };
+
     // Render the tree.
     let tree = TreeDisplay::new(
         &lock,
         markers.as_ref(),
         &latest,
+        &licenses,
         depth.into(),
         &prune,
         &package,
@@ -313,6 +365,30 @@ pub(crate) async fn tree(
         show_sizes,
     );
 
+    // The licenses of packages that aren't installed are unknown.
+    if let Some(uninstalled) = uninstalled {
+        let missing = tree
+            .packages()
+            .filter(|package| {
+                package
+                    .version()
+                    .is_some_and(|version| uninstalled.contains(&(package.name(), version)))
+            })
+            .map(|package| format!("`{}`", package.name()))
+            .sorted()
+            .dedup()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            warn_user!(
+                "Licenses are unknown for packages that are not installed in the project environment: {}; run `uv sync` to install them",
+                missing.join(", ")
+            );
+        }
+    }
+
+    // If requested, display only the packages with a matching license.
+    let tree = tree.with_licenses_filter(&licenses_filter);
+
     match format {
         TreeFormat::Text => print!("{tree}"),
         TreeFormat::Json => writeln!(
@@ -329,3 +405,25 @@ pub(crate) async fn tree(
 
     Ok(ExitStatus::Success)
 }
+
+/// Read the license of an installed distribution from its `METADATA` file.
+///
+/// Prefers the SPDX `License-Expression`, then a single-line `License` field, and finally any
+/// `License ::` Trove classifier.
+fn read_license(dist: &InstalledDist) -> Option<String> {
+    let contents = fs_err::read(dist.install_path().join("METADATA")).ok()?;
+    let metadata = Metadata23::parse(&contents).ok()?;
+    if let Some(expression) = metadata.license_expression {
+        return Some(expression);
+    }
+    if let Some(license) = metadata
+        .license
+        .filter(|license| !license.is_empty() && !license.contains('\n'))
+    {
+        return Some(license);
+    }
+    metadata.classifiers.iter().find_map(|classifier| {
+        let license = classifier.strip_prefix("License :: ")?;
+        Some(license.rsplit(" :: ").next().unwrap_or(license).to_string())
+    })
+}
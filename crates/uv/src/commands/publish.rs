@@ -17,7 +17,8 @@ use uv_distribution_types::{IndexCapabilities, IndexLocations, IndexUrl};
 use uv_errors::{ErrorOptions, Hints, write_error_chain_with_options};
 use uv_preview::{Preview, PreviewFeature};
 use uv_publish::{
-    CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult, check_trusted_publishing,
+    CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult, UploadDistribution,
+    check_trusted_publishing, generate_attestations, generate_signatures,
     group_files_for_publishing, upload, upload_two_phase,
 };
 use uv_redacted::DisplaySafeUrl;
@@ -28,6 +29,7 @@ use crate::commands::reporters::PublishReporter;
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn publish(
     paths: Vec<String>,
     publish_url: DisplaySafeUrl,
@@ -42,12 +44,19 @@ pub(crate) async fn publish(
     index_locations: IndexLocations,
     dry_run: bool,
     no_attestations: bool,
+    attestation: bool,
+    sign: bool,
+    identity: Option<&str>,
+    verify_metadata: bool,
+    list: bool,
+    skip_existing: bool,
     direct: bool,
     preview: Preview,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
-    if client_builder.is_offline() {
+    // Listing files doesn't require network access.
+    if client_builder.is_offline() && !list {
         bail!("Unable to publish files in offline mode");
     }
 
@@ -59,6 +68,14 @@ pub(crate) async fn publish(
         );
     }
 
+    if attestation && !preview.is_enabled(PreviewFeature::Pep740Attestation) {
+        warn_user_once!(
+            "The `--attestation` option is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Pep740Attestation
+        );
+    }
+
     let token_store = PyxTokenStore::from_settings()?;
 
     let (publish_url, check_url) = if let Some(index_name) = index {
@@ -104,10 +121,54 @@ pub(crate) async fn publish(
     };
 
     let mut groups = group_files_for_publishing(paths, no_attestations)?;
+    if attestation {
+        if dry_run || list {
+            debug!("Skipping attestation generation for dry run");
+        } else {
+            generate_attestations(&mut groups).await?;
+        }
+    }
+    if sign {
+        if dry_run || list {
+            debug!("Skipping signature generation for dry run");
+        } else {
+            generate_signatures(&mut groups, identity).await?;
+        }
+    }
+    if verify_metadata {
+        let mut invalid_count: usize = 0;
+        for group in &groups {
+            if let Err(err) = uv_publish::verify_metadata(&group.file, &group.filename)
+                .await
+                .map_err(|err| PublishError::PublishPrepare(group.file.clone(), Box::new(err)))
+            {
+                write_error_chain_with_options(
+                    &err,
+                    Hints::none(),
+                    ErrorOptions::default().with_stream(printer.stderr()),
+                )?;
+                invalid_count += 1;
+            }
+        }
+        if invalid_count > 0 {
+            let files = if invalid_count == 1 { "file" } else { "files" };
+            writeln!(
+                printer.stderr(),
+                "Found invalid metadata in {invalid_count} {files}, not uploading"
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+    }
     // Sort by filename first so the stable type sort preserves filename order within each type.
     groups.sort_by(|left, right| left.raw_filename.cmp(&right.raw_filename));
     // Sort by distribution type, with wheels before source distributions.
     groups.sort_by_key(|group| matches!(&group.filename, DistFilename::SourceDistFilename(_)));
+    if list {
+        if groups.is_empty() {
+            bail!("No files found to publish");
+        }
+        return list_files(&groups, printer).await;
+    }
     match groups.len() {
         0 => bail!("No files found to publish"),
         1 => {
@@ -319,6 +380,7 @@ pub(crate) async fn publish(
                     &token_store,
                     &upload_client,
                     &credentials,
+                    skip_existing,
                 )
                 .await
                 {
@@ -353,6 +415,7 @@ pub(crate) async fn publish(
                 &s3_client,
                 retry_policy,
                 &credentials,
+                skip_existing,
                 reporter.clone(),
             )
             .await?
@@ -366,6 +429,7 @@ pub(crate) async fn publish(
                 &token_store,
                 &upload_client,
                 &credentials,
+                skip_existing,
             )
             .await
             {
@@ -386,6 +450,7 @@ pub(crate) async fn publish(
                             retry_policy,
                             &credentials,
                             check_url_client.as_ref(),
+                            skip_existing,
                             &download_concurrency,
                             reporter.clone(),
                         )
@@ -427,6 +492,82 @@ pub(crate) async fn publish(
     Ok(ExitStatus::Success)
 }
 
+/// Validate the distributions and display their filename, size, and SHA-256 hash, without
+/// uploading them.
+async fn list_files(groups: &[UploadDistribution], printer: Printer) -> Result<ExitStatus> {
+    let mut rows = Vec::with_capacity(groups.len());
+    let mut error_count: usize = 0;
+
+    for group in groups {
+        let normalized_filename = group.filename.to_string();
+        if group.raw_filename != normalized_filename {
+            warn_user_once!(
+                "`{}` has a non-normalized filename (expected `{normalized_filename}`)",
+                group.raw_filename
+            );
+        }
+
+        let reporter = Arc::new(PublishReporter::single(printer));
+        let result = async {
+            let form_metadata =
+                FormMetadata::read_from_file(&group.file, &group.filename, reporter).await?;
+            uv_publish::verify_metadata(&group.file, &group.filename).await?;
+            Ok(form_metadata)
+        }
+        .await
+        .map_err(|err| PublishError::PublishPrepare(group.file.clone(), Box::new(err)));
+
+        match result {
+            Ok(form_metadata) => {
+                let size = fs_err::metadata(&group.file)?.len();
+                let (bytes, unit) = human_readable_bytes(size);
+                rows.push((
+                    group.raw_filename.as_str(),
+                    format!("{bytes:.1}{unit}"),
+                    form_metadata
+                        .sha256_digest()
+                        .unwrap_or_default()
+                        .to_string(),
+                ));
+            }
+            Err(err) => {
+                write_error_chain_with_options(
+                    &err,
+                    Hints::none(),
+                    ErrorOptions::default().with_stream(printer.stderr()),
+                )?;
+                error_count += 1;
+            }
+        }
+    }
+
+    let filename_width = rows
+        .iter()
+        .map(|(filename, ..)| filename.len())
+        .max()
+        .unwrap_or_default();
+    let size_width = rows
+        .iter()
+        .map(|(_, size, _)| size.len())
+        .max()
+        .unwrap_or_default();
+    for (filename, size, sha256) in &rows {
+        writeln!(
+            printer.stdout(),
+            "{filename:<filename_width$}  {size:>size_width$}  {}",
+            sha256.dimmed()
+        )?;
+    }
+
+    if error_count > 0 {
+        let failed = if error_count == 1 { "file" } else { "files" };
+        writeln!(printer.stderr(), "Found issues with {error_count} {failed}")?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Prompt {
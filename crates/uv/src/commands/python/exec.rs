@@ -0,0 +1,98 @@
+use std::ffi::OsString;
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_fs::Simplified;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
+
+use crate::child::run_to_completion;
+use crate::commands::ExitStatus;
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::printer::Printer;
+
+/// Run a command with the requested Python version on the `PATH`.
+pub(crate) async fn exec(
+    request: &str,
+    command: &[OsString],
+    install_mirrors: PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some((executable, args)) = command.split_first() else {
+        bail!("A command is required");
+    };
+
+    let python_request = PythonRequest::parse(request);
+    let reporter = PythonDownloadReporter::single(printer);
+
+    // Discover or download the requested interpreter.
+    let interpreter = PythonInstallation::find_or_download(
+        Some(&python_request),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+    )
+    .await?
+    .into_interpreter();
+
+    debug!(
+        "Using Python {} interpreter at: {}",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display()
+    );
+
+    // Prepend the interpreter's executable and scripts directories to the `PATH`.
+    let new_path = std::env::join_paths(
+        interpreter
+            .sys_executable()
+            .parent()
+            .into_iter()
+            .chain(std::iter::once(interpreter.scripts()))
+            .map(std::path::Path::to_path_buf)
+            .dedup()
+            .chain(
+                std::env::var_os(EnvVars::PATH)
+                    .as_ref()
+                    .iter()
+                    .flat_map(std::env::split_paths),
+            ),
+    )
+    .context("Failed to build new PATH variable")?;
+
+    let mut process = Command::new(executable);
+    process.args(args);
+    process.env(EnvVars::PATH, new_path);
+
+    // Spawn and wait for completion
+    // Standard input, output, and error streams are all inherited
+    let space = if args.is_empty() { "" } else { " " };
+    debug!(
+        "Running `{}{space}{}`",
+        executable.to_string_lossy(),
+        args.iter().map(|arg| arg.to_string_lossy()).join(" ")
+    );
+
+    let handle = process
+        .spawn()
+        .with_context(|| format!("Failed to spawn: `{}`", executable.to_string_lossy()))?;
+
+    run_to_completion(handle).await
+}
@@ -1,15 +1,17 @@
 use anyhow::Result;
+use serde::Serialize;
 use std::fmt::Write;
 use std::path::Path;
 
 use uv_cache::Cache;
+use uv_cli::PythonFindFormat;
 use uv_client::BaseClientBuilder;
 use uv_configuration::DependencyGroupsWithDefaults;
 use uv_errors::ErrorWithHints;
 use uv_fs::Simplified;
 use uv_python::{
-    ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference,
-    PythonRequest,
+    ConfigDiscovery, EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -29,6 +31,7 @@ pub(crate) async fn find(
     request: Option<String>,
     show_version: bool,
     resolve_links: bool,
+    output_format: PythonFindFormat,
     no_project: bool,
     system: bool,
     config_discovery: ConfigDiscovery,
@@ -119,20 +122,13 @@ pub(crate) async fn find(
         }
     }
 
-    if show_version {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            python.interpreter().python_version()
-        )?;
-    } else {
-        let path = if resolve_links {
-            dunce::canonicalize(python.interpreter().sys_executable())?
-        } else {
-            std::path::absolute(python.interpreter().sys_executable())?
-        };
-        writeln!(printer.stdout(), "{}", path.simplified_display())?;
-    }
+    write_interpreter(
+        python.interpreter(),
+        show_version,
+        resolve_links,
+        output_format,
+        printer,
+    )?;
 
     Ok(ExitStatus::Success)
 }
@@ -141,6 +137,7 @@ pub(crate) async fn find_script(
     script: Pep723ItemRef<'_>,
     show_version: bool,
     resolve_links: bool,
+    output_format: PythonFindFormat,
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -175,16 +172,62 @@ pub(crate) async fn find_script(
         Ok(ScriptInterpreter::Environment(environment)) => environment.into_interpreter(),
     };
 
+    write_interpreter(
+        &interpreter,
+        show_version,
+        resolve_links,
+        output_format,
+        printer,
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// The JSON representation of a found Python interpreter.
+#[derive(Debug, Serialize)]
+struct PythonFindOutput {
+    path: String,
+    version: String,
+    implementation: String,
+    arch: String,
+    managed: bool,
+}
+
+/// Write the path or version of the found Python interpreter.
+fn write_interpreter(
+    interpreter: &Interpreter,
+    show_version: bool,
+    resolve_links: bool,
+    output_format: PythonFindFormat,
+    printer: Printer,
+) -> Result<()> {
     if show_version {
         writeln!(printer.stdout(), "{}", interpreter.python_version())?;
+        return Ok(());
+    }
+
+    let path = if resolve_links {
+        dunce::canonicalize(interpreter.sys_executable())?
     } else {
-        let path = if resolve_links {
-            dunce::canonicalize(interpreter.sys_executable())?
-        } else {
-            std::path::absolute(interpreter.sys_executable())?
-        };
-        writeln!(printer.stdout(), "{}", path.simplified_display())?;
+        std::path::absolute(interpreter.sys_executable())?
+    };
+
+    match output_format {
+        PythonFindFormat::Text => {
+            writeln!(printer.stdout(), "{}", path.simplified_display())?;
+        }
+        PythonFindFormat::Json => {
+            let key = interpreter.key();
+            let output = PythonFindOutput {
+                path: path.simplified_display().to_string(),
+                version: interpreter.python_version().to_string(),
+                implementation: key.implementation().to_string(),
+                arch: key.arch().to_string(),
+                managed: interpreter.is_managed(),
+            };
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&output)?)?;
+        }
     }
 
-    Ok(ExitStatus::Success)
+    Ok(())
 }
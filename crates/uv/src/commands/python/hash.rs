@@ -0,0 +1,52 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_platform::Platform;
+use uv_python::PythonRequest;
+use uv_python::downloads::{ManagedPythonDownloadList, PythonDownloadRequest};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the expected SHA-256 hash of a Python download.
+pub(crate) async fn hash(
+    request: &str,
+    platform: Option<&str>,
+    python_downloads_json_url: Option<&str>,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some(download_request) =
+        PythonDownloadRequest::from_request(&PythonRequest::parse(request))
+    else {
+        bail!("No Python download is available for the request `{request}`");
+    };
+
+    let download_request = if let Some(platform) = platform {
+        let platform = Platform::from_str(platform)
+            .with_context(|| format!("Failed to parse platform `{platform}`"))?;
+        download_request.with_platform(&platform)
+    } else {
+        download_request
+    };
+    let download_request = download_request.fill()?;
+
+    let download_list =
+        ManagedPythonDownloadList::new(client_builder, cache, python_downloads_json_url).await?;
+    let download = download_list.find(&download_request)?;
+
+    let Some(sha256) = download.sha256() else {
+        bail!(
+            "No hash is available for the Python download `{}`",
+            download.key()
+        );
+    };
+    writeln!(printer.stdout(), "{sha256}")?;
+
+    Ok(ExitStatus::Success)
+}
@@ -85,7 +85,7 @@ pub(crate) async fn list(
     let mut output = BTreeSet::new();
     if let Some(base_download_request) = base_download_request {
         let download_request = match kinds {
-            PythonListKinds::Installed => None,
+            PythonListKinds::Installed | PythonListKinds::Managed => None,
             PythonListKinds::Downloads => Some(if all_platforms {
                 base_download_request
             } else if all_arches {
@@ -138,7 +138,7 @@ pub(crate) async fn list(
     }
 
     let installed = match kinds {
-        PythonListKinds::Installed | PythonListKinds::Default => {
+        PythonListKinds::Installed | PythonListKinds::Managed | PythonListKinds::Default => {
             // While usually [`PythonPreference::OnlyManaged`] means we can skip searching the
             // `PATH`, in `uv python list` we want to enumerate links to managed Python
             // interpreters for inspection. Consequently, we widen the preference here and
@@ -158,6 +158,11 @@ pub(crate) async fn list(
             // have expanded it above.
             installations
                 .retain(|installation| python_preference.allows_installation(installation));
+            // Only retain managed installations, excluding e.g. links to them on the `PATH`.
+            if matches!(kinds, PythonListKinds::Managed) {
+                installations
+                    .retain(|installation| matches!(installation.source(), PythonSource::Managed));
+            }
             Some(installations)
         }
         PythonListKinds::Downloads => None,
@@ -1,5 +1,7 @@
 pub(crate) mod dir;
+pub(crate) mod exec;
 pub(crate) mod find;
+pub(crate) mod hash;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
@@ -2,6 +2,7 @@ use std::fmt::Write;
 use std::path::Path;
 
 use anyhow::{Result, bail};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::debug;
 use uv_python::downloads::ManagedPythonDownloadList;
@@ -70,25 +71,41 @@ pub(crate) async fn pin(
     if rm {
         let Some(file) = version_file? else {
             if global {
-                bail!("No global Python pin found");
+                writeln!(printer.stderr(), "No global Python pin found")?;
+            } else {
+                writeln!(printer.stderr(), "No Python version file found")?;
             }
-            bail!("No Python version file found");
+            return Ok(ExitStatus::Success);
         };
 
         if !global && file.is_global() {
-            bail!("No Python version file found; use `--rm --global` to remove the global pin");
+            writeln!(
+                printer.stderr(),
+                "No Python version file found; use `--rm --global` to remove the global pin"
+            )?;
+            return Ok(ExitStatus::Success);
         }
 
+        let pins = file
+            .versions()
+            .map(|pin| format!("`{}`", pin.to_canonical_string()))
+            .join(", ");
+
         fs_err::tokio::remove_file(file.path()).await?;
         writeln!(
             printer.stdout(),
-            "Removed {} at `{}`",
+            "Removed {} at `{}`{}",
             if global {
                 "global Python pin"
             } else {
                 "Python version file"
             },
-            file.path().user_display()
+            file.path().user_display(),
+            if pins.is_empty() {
+                String::new()
+            } else {
+                format!(" (pinned to {pins})")
+            }
         )?;
         return Ok(ExitStatus::Success);
     }
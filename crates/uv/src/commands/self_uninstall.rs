@@ -0,0 +1,178 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use axoupdater::AxoUpdater;
+use console::Term;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::{Cache, rm_rf};
+use uv_fs::Simplified;
+use uv_python::managed::ManagedPythonInstallations;
+use uv_tool::InstalledTools;
+
+use crate::commands::self_update::find_receipt_path;
+use crate::commands::{ExitStatus, python_uninstall, tool_uninstall};
+use crate::printer::Printer;
+
+/// Uninstall uv, removing its executables and install receipt, managed Python installations,
+/// installed tools and, optionally, the cache.
+pub(crate) async fn self_uninstall(
+    remove_cache: bool,
+    yes: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let term = Term::stderr();
+    if !yes && !term.is_term() {
+        writeln!(
+            printer.stderr_important(),
+            "{}",
+            format_args!(
+                "{}{} Refusing to uninstall uv without confirmation in a non-interactive session (use `--yes` to proceed)",
+                "error".red().bold(),
+                ":".bold()
+            )
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let confirm = |prompt: String| -> Result<bool> {
+        if yes {
+            return Ok(true);
+        }
+        Ok(uv_console::confirm(&prompt, &term, false)?)
+    };
+
+    // Remove any managed Python installations.
+    let installations = ManagedPythonInstallations::from_settings(None)
+        .context("Failed to initialize toolchain settings")?;
+    if installations.root().exists()
+        && confirm(format!(
+            "Remove managed Python installations at `{}`?",
+            installations.root().user_display().cyan()
+        ))?
+    {
+        // Uninstall through `uv python uninstall --all`, such that the Python executables in the
+        // bin directory are removed too.
+        python_uninstall(None, vec![], true, printer).await?;
+        if installations.root().exists() {
+            remove_directory(installations.root(), printer)?;
+        }
+    }
+
+    // Remove any installed tools.
+    let tools = InstalledTools::from_settings().context("Failed to initialize tools settings")?;
+    if tools.root().exists()
+        && confirm(format!(
+            "Remove installed tools at `{}`?",
+            tools.root().user_display().cyan()
+        ))?
+    {
+        // Uninstall through `uv tool uninstall --all`, such that the tool executables in the bin
+        // directory are removed too.
+        tool_uninstall(vec![], printer).await?;
+        if tools.root().exists() {
+            remove_directory(tools.root(), printer)?;
+        }
+    }
+
+    // If requested, remove the cache.
+    if remove_cache
+        && cache.root().exists()
+        && confirm(format!(
+            "Remove the cache at `{}`?",
+            cache.root().user_display().cyan()
+        ))?
+    {
+        remove_directory(cache.root(), printer)?;
+    }
+
+    // Remove the `uv` and `uvx` executables, along with the install receipt.
+    let executables = executables()?;
+    let receipt = receipt();
+    if confirm(format!(
+        "Remove the uv executables ({})?",
+        executables
+            .iter()
+            .map(|executable| format!("`{}`", executable.user_display().cyan()))
+            .join(", ")
+    ))? {
+        #[cfg(windows)]
+        let itself = std::env::current_exe().ok();
+
+        for executable in &executables {
+            debug!("Removing executable: {}", executable.user_display());
+
+            // On Windows, the running executable can't be removed directly.
+            #[cfg(windows)]
+            if itself.as_ref().is_some_and(|itself| itself == executable) {
+                self_replace::self_delete()?;
+                writeln!(
+                    printer.stderr(),
+                    "Removed {}",
+                    executable.user_display().cyan()
+                )?;
+                continue;
+            }
+
+            fs_err::remove_file(executable)?;
+            writeln!(
+                printer.stderr(),
+                "Removed {}",
+                executable.user_display().cyan()
+            )?;
+        }
+
+        if let Some(receipt) = receipt {
+            debug!("Removing install receipt: {}", receipt.user_display());
+            fs_err::remove_file(&receipt)?;
+            writeln!(
+                printer.stderr(),
+                "Removed {}",
+                receipt.user_display().cyan()
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the directory at the given path, reporting the removal to the user.
+fn remove_directory(path: &Path, printer: Printer) -> Result<()> {
+    debug!("Removing directory: {}", path.user_display());
+    rm_rf(path).with_context(|| format!("Failed to remove: {}", path.user_display()))?;
+    writeln!(printer.stderr(), "Removed {}", path.user_display().cyan())?;
+    Ok(())
+}
+
+/// Return the paths to the `uv` executable and its sibling executables (e.g., `uvx`).
+fn executables() -> Result<Vec<PathBuf>> {
+    let itself = std::env::current_exe().context("Failed to determine the current executable")?;
+    let Some(parent) = itself.parent().map(Path::to_path_buf) else {
+        return Ok(vec![itself]);
+    };
+
+    let mut executables = vec![itself];
+    for name in ["uvx", "uvw"] {
+        let path = parent.join(format!("{name}{}", std::env::consts::EXE_SUFFIX));
+        if path.is_file() {
+            executables.push(path);
+        }
+    }
+    Ok(executables)
+}
+
+/// Return the path to the standalone install receipt, if the running executable was installed with
+/// the standalone installer.
+fn receipt() -> Option<PathBuf> {
+    let mut updater = AxoUpdater::new_for("uv");
+    let updater = updater.load_receipt().ok()?;
+    if !updater.check_receipt_is_for_this_executable().ok()? {
+        debug!("Install receipt is not for the current executable; leaving it in place");
+        return None;
+    }
+    find_receipt_path("uv").ok().flatten()
+}
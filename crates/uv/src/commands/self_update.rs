@@ -1,7 +1,7 @@
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::time::{Duration, SystemTimeError};
+use std::time::{Duration, SystemTime, SystemTimeError};
 
 use anyhow::{Context, Result};
 use axoupdater::{
@@ -16,6 +16,7 @@ use tokio::process::Command;
 use tracing::{debug, warn};
 use url::Url;
 use uv_bin_install::{Binary, find_matching_version};
+use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, RetriableError, WrappedReqwestError, fetch_with_url_fallback};
 use uv_fs::Simplified;
 use uv_pep440::{Version as Pep440Version, VersionSpecifier, VersionSpecifiers};
@@ -56,6 +57,9 @@ fn installer_download_url(
     ))
 }
 
+/// How long the result of `uv self update --check` is cached before querying the release API again.
+const CHECK_CACHE_TTL: Duration = Duration::from_hours(24);
+
 const AXOUPDATER_CONFIG_PATH: &str = "AXOUPDATER_CONFIG_PATH";
 const AXOUPDATER_CONFIG_WORKING_DIR: &str = "AXOUPDATER_CONFIG_WORKING_DIR";
 
@@ -64,9 +68,15 @@ pub(crate) async fn self_update(
     version: Option<String>,
     token: Option<String>,
     dry_run: bool,
+    check: bool,
+    cache: &Cache,
     printer: Printer,
     client_builder: BaseClientBuilder<'_>,
 ) -> Result<ExitStatus> {
+    if check {
+        return check_for_update(cache, printer, client_builder).await;
+    }
+
     if client_builder.is_offline() {
         writeln!(
             printer.stderr_important(),
@@ -263,6 +273,98 @@ pub(crate) async fn self_update(
     run_custom_updater(updater, printer, token.is_some()).await
 }
 
+/// Report whether a newer version of uv is available, without downloading it.
+///
+/// The latest version is cached in the cache directory for [`CHECK_CACHE_TTL`].
+async fn check_for_update(
+    cache: &Cache,
+    printer: Printer,
+    client_builder: BaseClientBuilder<'_>,
+) -> Result<ExitStatus> {
+    let current_version = Pep440Version::from_str(env!("CARGO_PKG_VERSION"))
+        .context("Failed to parse the current uv version")?;
+
+    let cache_path = cache
+        .bucket(CacheBucket::Binaries)
+        .join(Binary::Uv.to_string())
+        .join("latest-version");
+
+    let latest_version = if let Some(version) = read_cached_latest_version(&cache_path) {
+        debug!("Using cached latest uv version `{version}`");
+        version
+    } else {
+        if client_builder.is_offline() {
+            writeln!(
+                printer.stderr_important(),
+                "{}",
+                format_args!(
+                    "{}{} Checking for updates is not possible because network connectivity is disabled (i.e., with `--offline`)",
+                    "error".red().bold(),
+                    ":".bold()
+                )
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+
+        let retry_policy = client_builder.retry_policy();
+        let client = client_builder.retries(0).build()?;
+        let resolved = find_matching_version(Binary::Uv, None, None, &client, &retry_policy)
+            .await
+            .context("Failed to resolve the latest uv version")?;
+
+        // Caching is best-effort; a failure to write only means we'll query again next time.
+        if let Err(err) = write_cached_latest_version(&cache_path, &resolved.version) {
+            debug!("Failed to cache the latest uv version: {err}");
+        }
+
+        resolved.version
+    };
+
+    if is_update_needed(&current_version, &latest_version, false) {
+        writeln!(
+            printer.stderr_important(),
+            "uv {} is available (you have {})",
+            latest_version.to_string().bold().cyan(),
+            current_version.to_string().bold().cyan(),
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format_args!(
+                "You're on the latest version of uv ({})",
+                format!("v{current_version}").bold().white()
+            )
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read the cached latest uv version, if it exists and is still fresh.
+fn read_cached_latest_version(path: &Path) -> Option<Pep440Version> {
+    let modified = fs_err::metadata(path).ok()?.modified().ok()?;
+    if !is_check_cache_fresh(modified, SystemTime::now()) {
+        return None;
+    }
+    let contents = fs_err::read_to_string(path).ok()?;
+    Pep440Version::from_str(contents.trim()).ok()
+}
+
+/// Write the latest uv version to the cache.
+fn write_cached_latest_version(path: &Path, version: &Pep440Version) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    uv_fs::write_atomic_sync(path, version.to_string())
+}
+
+/// Returns `true` if a check result written at `modified` is still valid at `now`.
+fn is_check_cache_fresh(modified: SystemTime, now: SystemTime) -> bool {
+    now.duration_since(modified)
+        .is_ok_and(|age| age < CHECK_CACHE_TTL)
+}
+
 /// Returns `true` if the `source` is the official GitHub repository for uv, or
 /// if an installer base url override environment variable is set.
 fn is_official_public_uv_install(source: Option<&ReleaseSource>) -> bool {
@@ -607,7 +709,7 @@ fn load_receipt_modify_path(app_name: &str) -> Result<bool> {
 
 /// Find the receipt path for the given app name. Returns `Ok(None)` if the receipt
 /// definitely doesn't exist.
-fn find_receipt_path(app_name: &str) -> Result<Option<PathBuf>> {
+pub(super) fn find_receipt_path(app_name: &str) -> Result<Option<PathBuf>> {
     for prefix in receipt_prefixes(app_name)? {
         let receipt_path = prefix.join(format!("{app_name}-receipt.json"));
         if receipt_path.exists() {
@@ -954,6 +1056,16 @@ mod tests {
         assert!(official_target_version_specifiers(Some("v1.2.3")).is_err());
     }
 
+    #[test]
+    fn test_check_cache_fresh() {
+        let now = SystemTime::now();
+        assert!(is_check_cache_fresh(now, now));
+        assert!(is_check_cache_fresh(now - Duration::from_hours(1), now));
+        assert!(!is_check_cache_fresh(now - CHECK_CACHE_TTL, now));
+        // A modification time in the future is treated as stale.
+        assert!(!is_check_cache_fresh(now + Duration::from_mins(1), now));
+    }
+
     #[test]
     fn test_official_update_needed() {
         assert!(!is_update_needed(
@@ -2,6 +2,7 @@ use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{Result, bail};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::{debug, trace};
 
@@ -164,6 +165,7 @@ pub(crate) async fn install(
         ToolRequest::Package {
             executable,
             target: Target::Unspecified(from),
+            ..
         } => {
             let requirements = unresolved_target_requirements.clone().ok_or_else(|| {
                 anyhow::anyhow!("Expected parsed requirements for unresolved target `{from}`")
@@ -213,51 +215,55 @@ pub(crate) async fn install(
 
             requirement
         }
-        // Ex) `ruff@0.6.0`
+        // Ex) `ruff@0.6.0` or `ruff@latest`
         ToolRequest::Package {
-            target: Target::Version(.., name, extras, version),
+            target:
+                target @ (Target::Version(.., name, extras, _) | Target::Latest(.., name, extras)),
             ..
         } => {
             if editable {
                 bail!("`--editable` is only supported for local packages");
             }
 
+            let specifier = if target.is_pinned() {
+                target
+                    .requested_version()
+                    .cloned()
+                    .map(VersionSpecifier::equals_version)
+                    .into_iter()
+                    .collect()
+            } else {
+                VersionSpecifiers::empty()
+            };
             Requirement {
                 name: name.clone(),
                 extras: extras.clone(),
                 groups: Box::new([]),
                 marker: MarkerTree::default(),
                 source: RequirementSource::Registry {
-                    specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
-                        version.clone(),
-                    )),
+                    specifier,
                     index: None,
                     conflict: None,
                 },
                 origin: None,
             }
         }
-        // Ex) `ruff@latest`
+        // Ex) `ruff@latest-2024-01-01`
         ToolRequest::Package {
-            target: Target::Latest(.., name, extras),
+            target: Target::LatestAsOf(_, name, extras, date),
             ..
         } => {
-            if editable {
-                bail!("`--editable` is only supported for local packages");
-            }
-
-            Requirement {
-                name: name.clone(),
-                extras: extras.clone(),
-                groups: Box::new([]),
-                marker: MarkerTree::default(),
-                source: RequirementSource::Registry {
-                    specifier: VersionSpecifiers::empty(),
-                    index: None,
-                    conflict: None,
-                },
-                origin: None,
-            }
+            let requirement = if extras.is_empty() {
+                name.to_string()
+            } else {
+                format!("{name}[{}]", extras.iter().join(","))
+            };
+            bail!(
+                "`{}` is only supported by `{}`; use `{}` to install the latest version as of {date}",
+                format!("{requirement}@latest-{date}").cyan(),
+                "uv tool run".cyan(),
+                format!("uv tool install {requirement} --exclude-newer {date}").green(),
+            );
         }
         // Ex) `python`
         ToolRequest::Python { .. } => {
@@ -1,9 +1,13 @@
 use std::str::FromStr;
 
+use jiff::civil::Date;
 use tracing::debug;
 
+use uv_distribution_types::UnresolvedRequirement;
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::Version;
+use uv_pep508::{Scheme, split_scheme};
+use uv_pypi_types::ParsedUrl;
 use uv_python::PythonRequest;
 
 pub(crate) mod common;
@@ -31,6 +35,8 @@ pub(crate) enum ToolRequest<'a> {
         executable: Option<&'a str>,
         /// The target to install or run (e.g., `ruff@latest` or `ruff==0.6.0`).
         target: Target<'a>,
+        /// The interpreter to install or run the target with (e.g., via `--python 3.12`), if any.
+        python: Option<PythonRequest>,
     },
 }
 
@@ -56,12 +62,26 @@ impl<'a> ToolRequest<'a> {
         } else {
             // Otherwise the command is a Python package, like `ruff` or `ruff@0.6.0`.
             Ok(Self::Package {
-                target: Target::parse(component_to_parse),
+                target: Target::parse_checked(component_to_parse)?,
                 executable,
+                python: None,
             })
         }
     }
 
+    /// Create a request for a package target to be installed or run with the given interpreter.
+    fn package_with_python(
+        executable: Option<&'a str>,
+        target: Target<'a>,
+        python: PythonRequest,
+    ) -> Self {
+        Self::Package {
+            executable,
+            target,
+            python: Some(python),
+        }
+    }
+
     /// Returns `true` if the target is `latest`.
     fn is_latest(&self) -> bool {
         matches!(
@@ -72,6 +92,17 @@ impl<'a> ToolRequest<'a> {
             }
         )
     }
+
+    /// Returns the date of a `latest-<date>` target, if any.
+    fn latest_as_of(&self) -> Option<Date> {
+        match self {
+            Self::Package {
+                target: Target::LatestAsOf(.., date),
+                ..
+            } => Some(*date),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -79,12 +110,92 @@ pub(crate) enum Target<'a> {
     /// e.g., `ruff`
     Unspecified(&'a str),
     /// e.g., `ruff[extra]@0.6.0`
+    ///
+    /// Any valid PEP 440 version is retained as-is, including post-release, pre-release,
+    /// development, and local segments (e.g., `ruff@0.6.0.post1` or `ruff@0.6.0+local`).
     Version(&'a str, PackageName, Box<[ExtraName]>, Version),
     /// e.g., `ruff[extra]@latest`
     Latest(&'a str, PackageName, Box<[ExtraName]>),
+    /// e.g., `ruff[extra]@latest-2024-01-01` or `ruff[extra]@2024-01-01`
+    ///
+    /// The latest version published on or before the given date.
+    LatestAsOf(&'a str, PackageName, Box<[ExtraName]>, Date),
+}
+
+/// The kind of requirement that an unspecified [`Target`] parses to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TargetKind {
+    /// e.g., `ruff>=0.5` or `ruff @ https://...`
+    RequirementSpec,
+    /// e.g., `git+https://github.com/astral-sh/ruff`
+    Url,
+    /// e.g., `./ruff` or `ruff-0.6.0-py3-none-any.whl`
+    LocalPath,
+}
+
+impl From<&UnresolvedRequirement> for TargetKind {
+    /// Classify a target by its parsed requirement, such that the classification always agrees
+    /// with the requirement parser (e.g., `foo/bar` is a relative path).
+    fn from(requirement: &UnresolvedRequirement) -> Self {
+        match requirement {
+            UnresolvedRequirement::Named(_) => Self::RequirementSpec,
+            UnresolvedRequirement::Unnamed(requirement) => match &requirement.url.parsed_url {
+                ParsedUrl::Path(_) | ParsedUrl::Directory(_) => Self::LocalPath,
+                ParsedUrl::GitDirectory(_) | ParsedUrl::GitPath(_) | ParsedUrl::Archive(_) => {
+                    Self::Url
+                }
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for TargetKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RequirementSpec => write!(f, "requirement"),
+            Self::Url => write!(f, "URL"),
+            Self::LocalPath => write!(f, "local path"),
+        }
+    }
+}
+
+/// An error that can occur when parsing a [`Target`].
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum TargetParseError {
+    #[error("Missing version after `@` in `{0}`; use `{1}` to omit the version")]
+    EmptyVersion(String, String),
 }
 
 impl<'a> Target<'a> {
+    /// Parse a target into a command name and a requirement, rejecting an empty version request
+    /// (e.g., `ruff@`).
+    ///
+    /// In contrast, [`Target::parse`] treats the whole target as the command in this case.
+    fn parse_checked(target: &'a str) -> Result<Self, TargetParseError> {
+        if let Some((name, version)) = target.split_once('@')
+            && version.trim().is_empty()
+        {
+            return Err(TargetParseError::EmptyVersion(
+                target.to_string(),
+                name.trim().to_string(),
+            ));
+        }
+        Ok(Self::parse(target))
+    }
+
+    /// Returns `true` if the target pins a concrete version (e.g., `ruff@0.6.0`).
+    fn is_pinned(&self) -> bool {
+        matches!(self, Self::Version(..))
+    }
+
+    /// Returns the version requested by the target (e.g., `0.6.0` for `ruff@0.6.0`), if any.
+    fn requested_version(&self) -> Option<&Version> {
+        match self {
+            Self::Version(.., version) => Some(version),
+            Self::Unspecified(..) | Self::Latest(..) | Self::LatestAsOf(..) => None,
+        }
+    }
+
     /// Parse a target into a command name and a requirement.
     fn parse(target: &'a str) -> Self {
         // e.g. `ruff`, no special handling
@@ -92,12 +203,23 @@ impl<'a> Target<'a> {
             return Self::Unspecified(target);
         };
 
+        // e.g. `ruff @ 0.6.0`, treat the same as `ruff@0.6.0`. The unparsed `target` is retained
+        // as-is for the fallbacks below, since whitespace may be meaningful in a requirement.
+        let name = name.trim();
+        let version = version.trim();
+
         // e.g. `ruff@`, warn and treat the whole thing as the command
         if version.is_empty() {
             debug!("Ignoring empty version request in command");
             return Self::Unspecified(target);
         }
 
+        // e.g. `ruff @ https://...`, a PEP 508 direct reference rather than a version request
+        if is_url(version) {
+            debug!("Treating direct reference `{target}` as a requirement");
+            return Self::Unspecified(target);
+        }
+
         // Split into name and extras (e.g., `flask[dotenv]`).
         let (executable, extras) = match name.split_once('[') {
             Some((executable, extras)) => {
@@ -131,10 +253,23 @@ impl<'a> Target<'a> {
         match version {
             // e.g., `ruff@latest`
             "latest" => Self::Latest(executable, name, extras),
-            // e.g., `ruff@0.6.0`
+            // e.g., `ruff@0.6.0`, `ruff@0.6.0.post1`, or `ruff@0.6.0-1` (an implicit post-release).
+            //
+            // Wheel build tags (e.g., the `1` in `ruff-0.6.0-1-py3-none-any.whl`) are not part of
+            // the version, so a suffix like `ruff@0.6.0-build1` is not a valid version.
             version if let Ok(version) = Version::from_str(version) => {
                 Self::Version(executable, name, extras, version)
             }
+            // e.g., `ruff@latest-2024-01-01` or `ruff@2024-01-01`
+            //
+            // Checked after versions, such that a calendar version like `ruff@20240101` is
+            // retained as a version.
+            version
+                if let Ok(date) =
+                    Date::from_str(version.strip_prefix("latest-").unwrap_or(version)) =>
+            {
+                Self::LatestAsOf(executable, name, extras, date)
+            }
             version => {
                 // e.g. `ruff@invalid`, warn and treat the whole thing as the command
                 debug!("Ignoring invalid version request `{version}` in command");
@@ -144,8 +279,16 @@ impl<'a> Target<'a> {
     }
 }
 
+/// Returns `true` if the string starts with a supported URL scheme (e.g., `https://` or
+/// `git+https://`), as opposed to, e.g., a Windows drive letter.
+fn is_url(target: &str) -> bool {
+    split_scheme(target).is_some_and(|(scheme, _)| Scheme::parse(scheme).is_some())
+}
+
 #[cfg(test)]
 mod tests {
+    use uv_requirements::RequirementsSpecification;
+
     use super::*;
 
     #[test]
@@ -207,4 +350,231 @@ mod tests {
         let expected = Target::Unspecified("flask[dotenv]]");
         assert_eq!(target, expected);
     }
+
+    #[test]
+    fn target_requested_version() {
+        let target = Target::parse("flask");
+        assert!(!target.is_pinned());
+        assert_eq!(target.requested_version(), None);
+
+        let target = Target::parse("flask@3.0.0");
+        assert!(target.is_pinned());
+        assert_eq!(target.requested_version(), Some(&Version::new([3, 0, 0])));
+
+        let target = Target::parse("flask@latest");
+        assert!(!target.is_pinned());
+        assert_eq!(target.requested_version(), None);
+
+        let target = Target::parse("flask@latest-2024-01-01");
+        assert!(!target.is_pinned());
+        assert_eq!(target.requested_version(), None);
+    }
+
+    #[test]
+    fn parse_target_version_segments() {
+        // Post-release, development, and local segments are retained in the version.
+        for version in ["1.0.0.post3", "1.0.0.dev1", "1.0.0rc1", "1.0.0+build3"] {
+            let target = format!("flask@{version}");
+            let expected = Target::Version(
+                "flask",
+                PackageName::from_str("flask").unwrap(),
+                Box::new([]),
+                Version::from_str(version).unwrap(),
+            );
+            assert_eq!(Target::parse(&target), expected);
+        }
+
+        // A numeric suffix is an implicit post-release, per PEP 440.
+        let target = Target::parse("flask@1.0.0-3");
+        let expected = Target::Version(
+            "flask",
+            PackageName::from_str("flask").unwrap(),
+            Box::new([]),
+            Version::from_str("1.0.0.post3").unwrap(),
+        );
+        assert_eq!(target, expected);
+
+        // Wheel build tags are not versions, so the target is treated as a command.
+        let target = Target::parse("flask@1.0.0-build3");
+        let expected = Target::Unspecified("flask@1.0.0-build3");
+        assert_eq!(target, expected);
+    }
+
+    #[test]
+    fn parse_target_whitespace() {
+        let expected = Target::Version(
+            "flask",
+            PackageName::from_str("flask").unwrap(),
+            Box::new([]),
+            Version::new([3, 0, 0]),
+        );
+        assert_eq!(Target::parse("flask @ 3.0.0"), expected);
+        assert_eq!(Target::parse("flask@ 3.0.0"), expected);
+        assert_eq!(Target::parse(" flask @3.0.0 "), expected);
+
+        let target = Target::parse("flask[dotenv] @ latest");
+        let expected = Target::Latest(
+            "flask",
+            PackageName::from_str("flask").unwrap(),
+            Box::new([ExtraName::from_str("dotenv").unwrap()]),
+        );
+        assert_eq!(target, expected);
+
+        // An empty version is still ignored.
+        let target = Target::parse("flask @ ");
+        let expected = Target::Unspecified("flask @ ");
+        assert_eq!(target, expected);
+
+        // The original target is retained when falling back.
+        let target = Target::parse("flask @ invalid");
+        let expected = Target::Unspecified("flask @ invalid");
+        assert_eq!(target, expected);
+    }
+
+    #[test]
+    fn parse_target_latest_as_of() {
+        let expected = Target::LatestAsOf(
+            "flask",
+            PackageName::from_str("flask").unwrap(),
+            Box::new([ExtraName::from_str("dotenv").unwrap()]),
+            Date::constant(2024, 1, 1),
+        );
+        assert_eq!(Target::parse("flask[dotenv]@latest-2024-01-01"), expected);
+        assert_eq!(Target::parse("flask[dotenv]@2024-01-01"), expected);
+
+        // An invalid date is treated as a requirement.
+        let target = Target::parse("flask@latest-2024-13-01");
+        let expected = Target::Unspecified("flask@latest-2024-13-01");
+        assert_eq!(target, expected);
+
+        let target = Target::parse("flask@2024-02-30");
+        let expected = Target::Unspecified("flask@2024-02-30");
+        assert_eq!(target, expected);
+
+        // A calendar version is a version, not a date.
+        let target = Target::parse("flask@20240101");
+        let expected = Target::Version(
+            "flask",
+            PackageName::from_str("flask").unwrap(),
+            Box::new([]),
+            Version::new([20_240_101]),
+        );
+        assert_eq!(target, expected);
+    }
+
+    #[test]
+    fn parse_target_direct_reference() {
+        let target = Target::parse("pkg @ https://example.com/pkg.whl");
+        let expected = Target::Unspecified("pkg @ https://example.com/pkg.whl");
+        assert_eq!(target, expected);
+
+        // The `@` in a URL authority is not treated as a version separator.
+        let target = Target::parse("pkg@git+https://user@example.com/pkg.git");
+        let expected = Target::Unspecified("pkg@git+https://user@example.com/pkg.git");
+        assert_eq!(target, expected);
+    }
+
+    #[test]
+    fn parse_target_checked() {
+        // An empty version is an error when checked, but is otherwise ignored.
+        assert!(matches!(
+            Target::parse_checked("ruff@"),
+            Err(TargetParseError::EmptyVersion(..))
+        ));
+        assert_eq!(Target::parse("ruff@"), Target::Unspecified("ruff@"));
+
+        let target = Target::parse_checked("ruff@0.6.0").unwrap();
+        let expected = Target::Version(
+            "ruff",
+            PackageName::from_str("ruff").unwrap(),
+            Box::new([]),
+            Version::new([0, 6, 0]),
+        );
+        assert_eq!(target, expected);
+
+        let target = Target::parse_checked("ruff").unwrap();
+        assert_eq!(target, Target::Unspecified("ruff"));
+    }
+
+    #[test]
+    fn target_kind() {
+        fn kind(target: &str) -> TargetKind {
+            let specification = RequirementsSpecification::parse_package(target).unwrap();
+            TargetKind::from(&specification.requirement)
+        }
+
+        assert_eq!(kind("./x"), TargetKind::LocalPath);
+        assert_eq!(kind("../x"), TargetKind::LocalPath);
+        assert_eq!(kind("ruff-0.6.0-py3-none-any.whl"), TargetKind::LocalPath);
+        assert_eq!(
+            kind("git+https://github.com/astral-sh/ruff"),
+            TargetKind::Url
+        );
+        assert_eq!(
+            kind("git+https://github.com/astral-sh/ruff.git@main"),
+            TargetKind::Url
+        );
+        assert_eq!(
+            kind("https://files.pythonhosted.org/packages/ruff-0.6.0.tar.gz"),
+            TargetKind::Url
+        );
+        assert_eq!(kind("ruff>=0.5"), TargetKind::RequirementSpec);
+        assert_eq!(
+            kind("ruff @ git+https://github.com/astral-sh/ruff"),
+            TargetKind::RequirementSpec
+        );
+    }
+
+    #[test]
+    fn parse_request() {
+        let request = ToolRequest::parse("python@3.12", None).unwrap();
+        assert_eq!(
+            request,
+            ToolRequest::Python {
+                executable: None,
+                request: PythonRequest::parse("3.12"),
+            }
+        );
+
+        let request = ToolRequest::parse("ruff@0.6.0", None).unwrap();
+        assert_eq!(
+            request,
+            ToolRequest::Package {
+                executable: None,
+                target: Target::Version(
+                    "ruff",
+                    PackageName::from_str("ruff").unwrap(),
+                    Box::new([]),
+                    Version::new([0, 6, 0]),
+                ),
+                python: None,
+            }
+        );
+    }
+
+    #[test]
+    fn package_with_python() {
+        let request = ToolRequest::package_with_python(
+            None,
+            Target::parse("ruff@0.6.0"),
+            PythonRequest::parse("3.11"),
+        );
+        assert_eq!(
+            request,
+            ToolRequest::Package {
+                executable: None,
+                target: Target::Version(
+                    "ruff",
+                    PackageName::from_str("ruff").unwrap(),
+                    Box::new([]),
+                    Version::new([0, 6, 0]),
+                ),
+                python: Some(PythonRequest::parse("3.11")),
+            }
+        );
+
+        // Parsed package targets don't carry an interpreter.
+        let request = ToolRequest::parse("ruff", None).unwrap();
+        assert!(matches!(request, ToolRequest::Package { python: None, .. }));
+    }
 }
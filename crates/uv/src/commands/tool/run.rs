@@ -19,8 +19,8 @@ use uv_configuration::{Concurrency, Constraints, GitLfsSetting, TargetTriple};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::InstalledDist;
 use uv_distribution_types::{
-    IndexCapabilities, IndexUrl, Name, NameRequirementSpecification, Requirement,
-    RequirementSource, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    ExcludeNewerValue, IndexCapabilities, IndexUrl, Name, NameRequirementSpecification,
+    Requirement, RequirementSource, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
@@ -53,7 +53,7 @@ use crate::commands::project::{
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::{ToolPython, matching_packages, refine_interpreter};
-use crate::commands::tool::{Target, ToolRequest};
+use crate::commands::tool::{Target, TargetKind, ToolRequest};
 use crate::commands::{
     UvError, diagnostics, project::environment::CachedEnvironment, read_env_files,
 };
@@ -229,7 +229,15 @@ pub(crate) async fn run(
         }
     }
 
-    let request = ToolRequest::parse(target, from.as_deref())?;
+    // Attach the `--python` request to package targets, such that the request is self-contained.
+    let request = match ToolRequest::parse(target, from.as_deref())? {
+        ToolRequest::Package {
+            executable, target, ..
+        } if let Some(python) = python.as_deref() => {
+            ToolRequest::package_with_python(executable, target, PythonRequest::parse(python))
+        }
+        request => request,
+    };
 
     // If the user passed, e.g., `ruff@latest`, refresh the cache.
     let cache = if request.is_latest() {
@@ -238,6 +246,19 @@ pub(crate) async fn run(
         cache
     };
 
+    // If the user passed, e.g., `ruff@latest-2024-01-01`, exclude distributions published after
+    // the given date for this invocation.
+    let settings = if let Some(date) = request.latest_as_of() {
+        let exclude_newer =
+            ExcludeNewerValue::from_str(&date.to_string()).map_err(anyhow::Error::msg)?;
+        debug!("Excluding distributions newer than `{exclude_newer}`");
+        let mut settings = settings;
+        settings.resolver.exclude_newer.global = Some(exclude_newer);
+        settings
+    } else {
+        settings
+    };
+
     // Get or create a compatible environment in which to execute the tool.
     let result = Box::pin(get_or_create_environment(
         &request,
@@ -324,7 +345,18 @@ pub(crate) async fn run(
         )),
     };
 
-    if let Some(ref provider_hints) = provider_hints {
+    // If no package provides the executable, but the environment contains a module of the same
+    // name with a `__main__.py`, run it with `python -m` instead.
+    let main_module = match &provider_hints {
+        Some(provider_hints) if provider_hints.not_from_any() => {
+            find_main_module(executable, &environment)
+        }
+        _ => None,
+    };
+
+    if let Some(ref provider_hints) = provider_hints
+        && main_module.is_none()
+    {
         if provider_hints.not_from_any() {
             if !explicit_from {
                 // If the user didn't use `--from` and the command isn't in the environment, we're now
@@ -344,7 +376,14 @@ pub(crate) async fn run(
     }
 
     // Construct the command
-    let mut process = if cfg!(windows) {
+    let mut process = if let Some(module) = &main_module {
+        debug!(
+            "No executable named `{executable}` found; running the `{module}` module with `python -m`"
+        );
+        let mut process = Command::new(environment.interpreter().sys_executable());
+        process.arg("-m").arg(module);
+        process
+    } else if cfg!(windows) {
         WindowsRunnable::from_script_path(environment.scripts(), executable.as_ref()).into()
     } else {
         Command::new(executable)
@@ -412,6 +451,24 @@ fn get_entrypoints(
     )?)
 }
 
+/// Return the name of the module matching the executable, if it can be run with `python -m`.
+///
+/// The module must be a package with a `__main__.py`, e.g., `foo_bar/__main__.py` for the
+/// executable `foo-bar`.
+fn find_main_module(executable: &str, environment: &PythonEnvironment) -> Option<String> {
+    let module = executable.replace('-', "_");
+    if !module
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+    environment
+        .site_packages()
+        .any(|site_packages| site_packages.join(&module).join("__main__.py").is_file())
+        .then_some(module)
+}
+
 /// Display a list of tools that provide the executable.
 ///
 /// If there is no package providing the executable, we will display a message to how to install a package.
@@ -701,15 +758,24 @@ async fn get_or_create_environment(
         ToolRequest::Package {
             target: Target::Unspecified(requirement),
             ..
-        } => Some(RequirementsSpecification::parse_package(requirement)?),
+        } => {
+            let specification = RequirementsSpecification::parse_package(requirement)?;
+            debug!(
+                "Parsed tool target `{requirement}` as a {}",
+                TargetKind::from(&specification.requirement)
+            );
+            Some(specification)
+        }
         _ => None,
     };
 
-    // Determine explicit Python version requests
-    let explicit_python_request = python.map(PythonRequest::parse);
-    let tool_python_request = match request {
-        ToolRequest::Python { request, .. } => Some(request.clone()),
-        ToolRequest::Package { .. } => None,
+    // Determine explicit Python version requests. For package targets, the `--python` request is
+    // carried by the tool request itself.
+    let (explicit_python_request, tool_python_request) = match request {
+        ToolRequest::Python { request, .. } => {
+            (python.map(PythonRequest::parse), Some(request.clone()))
+        }
+        ToolRequest::Package { python, .. } => (python.clone(), None),
     };
 
     // Resolve an argument-derived Python request, if any.
@@ -771,6 +837,7 @@ async fn get_or_create_environment(
         ToolRequest::Package {
             executable: request_executable,
             target,
+            ..
         } => {
             let (executable, requirement) = match target {
                 // Ex) `ruff>=0.6.0`
@@ -824,40 +891,30 @@ async fn get_or_create_environment(
 
                     (executable, requirement)
                 }
-                // Ex) `ruff@0.6.0`
-                Target::Version(executable, name, extras, version) => {
+                // Ex) `ruff@0.6.0`, `ruff@latest`, or `ruff@latest-2024-01-01`
+                target @ (Target::Version(executable, name, extras, _)
+                | Target::Latest(executable, name, extras)
+                | Target::LatestAsOf(executable, name, extras, _)) => {
                     let executable = request_executable
                         .map(ToString::to_string)
                         .unwrap_or_else(|| (*executable).to_string());
-                    let requirement = Requirement {
-                        name: name.clone(),
-                        extras: extras.clone(),
-                        groups: Box::new([]),
-                        marker: MarkerTree::default(),
-                        source: RequirementSource::Registry {
-                            specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
-                                version.clone(),
-                            )),
-                            index: None,
-                            conflict: None,
-                        },
-                        origin: None,
+                    let specifier = if target.is_pinned() {
+                        target
+                            .requested_version()
+                            .cloned()
+                            .map(VersionSpecifier::equals_version)
+                            .into_iter()
+                            .collect()
+                    } else {
+                        VersionSpecifiers::empty()
                     };
-
-                    (executable, requirement)
-                }
-                // Ex) `ruff@latest`
-                Target::Latest(executable, name, extras) => {
-                    let executable = request_executable
-                        .map(ToString::to_string)
-                        .unwrap_or_else(|| (*executable).to_string());
                     let requirement = Requirement {
                         name: name.clone(),
                         extras: extras.clone(),
                         groups: Box::new([]),
                         marker: MarkerTree::default(),
                         source: RequirementSource::Registry {
-                            specifier: VersionSpecifiers::empty(),
+                            specifier,
                             index: None,
                             conflict: None,
                         },
@@ -877,7 +934,7 @@ async fn get_or_create_environment(
 
     // For `@latest`, fetch the latest version and create a constraint.
     let latest = if let ToolRequest::Package {
-        target: Target::Latest(_, name, _),
+        target: Target::Latest(_, name, _) | Target::LatestAsOf(_, name, _, _),
         ..
     } = &request
     {
@@ -1000,25 +1057,47 @@ async fn get_or_create_environment(
     .await?;
 
     // Check if the tool is already installed in a compatible environment.
-    if !isolated && !request.is_latest() {
+    if !isolated && !request.is_latest() && request.latest_as_of().is_none() {
         let installed_tools = InstalledTools::from_settings()?.init()?;
         let _lock = installed_tools.lock().await?;
 
         if let ToolRequirement::Package { requirement, .. } = &from {
+            let receipt = installed_tools
+                .get_tool_receipt(&requirement.name)
+                .ok()
+                .flatten();
             let existing_environment = installed_tools
                 .get_environment(&requirement.name, cache)?
                 .filter(|environment| {
                     python_request.as_ref().is_none_or(|python_request| {
-                        python_request.satisfied(environment.environment().interpreter(), cache)
+                        // If the tool was installed with a version request at least as specific
+                        // as this one (e.g., `3.11.5` for `3.11`), reuse it without checking the
+                        // interpreter. Names and paths (e.g., `python3`) may resolve to a
+                        // different interpreter over time, so they're always checked.
+                        let pinned_version = receipt
+                            .as_ref()
+                            .and_then(|receipt| receipt.python().as_ref())
+                            .is_some_and(|installed| {
+                                matches!(
+                                    (installed, python_request),
+                                    (
+                                        PythonRequest::Version(..)
+                                            | PythonRequest::ImplementationVersion(..),
+                                        PythonRequest::Version(..)
+                                            | PythonRequest::ImplementationVersion(..)
+                                    )
+                                ) && installed.satisfies(python_request)
+                            });
+                        pinned_version
+                            || python_request
+                                .satisfied(environment.environment().interpreter(), cache)
                     })
                 });
 
             // Check if the installed packages meet the requirements.
             if let Some(environment) = existing_environment {
-                if installed_tools
-                    .get_tool_receipt(&requirement.name)
-                    .ok()
-                    .flatten()
+                if receipt
+                    .as_ref()
                     .is_some_and(|receipt| ToolOptions::from(options) == *receipt.options())
                 {
                     let ResolverInstallerSettings {
@@ -4,6 +4,7 @@ use std::str::FromStr;
 use std::vec;
 
 use anyhow::Result;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use thiserror::Error;
 use tracing::warn;
@@ -16,12 +17,13 @@ use uv_configuration::{
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildRequires, Index, IndexLocations,
+    ConfigSettings, DependencyMetadata, ExtraBuildRequires, Index, IndexLocations, Name,
     PackageConfigSettings, Requirement,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_normalize::DefaultGroups;
+use uv_installer::SitePackages;
+use uv_normalize::{DefaultGroups, PackageName};
 use uv_preview::Preview;
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference,
@@ -63,6 +65,15 @@ enum VenvError {
 
     #[error("Failed to resolve `--find-links` entry")]
     FlatIndex(#[source] uv_client::FlatIndexError),
+
+    #[error("Failed to read the installed packages in the virtual environment")]
+    SitePackages(#[source] anyhow::Error),
+
+    #[error(
+        "The virtual environment contains seed packages, but `--without-pip` was provided: {}",
+        _0.iter().map(|name| format!("`{name}`")).join(", ")
+    )]
+    SeedPresent(Vec<PackageName>),
 }
 
 /// Create a virtual environment.
@@ -215,7 +226,7 @@ pub(crate) async fn venv(
 
     let with_seed = match seed {
         Seed::Enabled => " with seed packages",
-        Seed::Disabled => "",
+        Seed::Disabled | Seed::Excluded => "",
     };
     if centralized_workspace.is_some() {
         writeln!(
@@ -283,6 +294,23 @@ pub(crate) async fn venv(
     )
     .map_err(VenvError::Creation)?;
 
+    // Ensure that no seed packages are present, e.g., in an existing environment.
+    if let Seed::Excluded = seed {
+        let site_packages =
+            SitePackages::from_environment(&venv).map_err(VenvError::SitePackages)?;
+        let present = site_packages
+            .iter()
+            .map(Name::name)
+            .filter(|name| matches!(name.as_str(), "pip" | "setuptools" | "wheel"))
+            .cloned()
+            .sorted()
+            .dedup()
+            .collect::<Vec<_>>();
+        if !present.is_empty() {
+            return Err(VenvError::SeedPresent(present).into());
+        }
+    }
+
     // Install seed packages.
     if let Seed::Enabled = seed {
         // Extract the interpreter.
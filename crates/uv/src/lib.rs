@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::ffi::OsString;
 use std::fmt::Write;
 use std::io::stdout;
+use std::num::NonZeroUsize;
 #[cfg(feature = "self-update")]
 use std::ops::Bound;
 use std::path::Path;
@@ -24,16 +25,16 @@ use tracing::{debug, instrument, trace};
 use crate::install_source::InstallSource;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
-#[cfg(feature = "self-update")]
-use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
     CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
     PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
     WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs, options::ArgumentError,
 };
+#[cfg(feature = "self-update")]
+use uv_cli::{SelfUninstallArgs, SelfUpdateArgs};
 use uv_client::BaseClientBuilder;
-use uv_configuration::min_stack_size;
+use uv_configuration::{Concurrency, min_stack_size};
 use uv_flags::EnvironmentFlags;
 use uv_fs::{CWD, Simplified, normalize_path};
 #[cfg(feature = "self-update")]
@@ -509,11 +510,18 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
     if global_initialization.needs_initialization()
         && globals.preview.is_enabled(PreviewFeature::AdjustUlimit)
     {
-        match uv_unix::adjust_open_file_limit() {
-            Ok(_) | Err(uv_unix::OpenFileLimitError::AlreadySufficient { .. }) => {}
-            // TODO(zanieb): When moving out of preview, consider changing this to a log instead of
-            // a warning because it's okay if we fail here.
-            Err(err) => warn_user!("{err}"),
+        if environment.no_rlimit.value == Some(true) {
+            debug!(
+                "Skipping open file limit adjustment due to `{}`",
+                EnvVars::UV_NO_RLIMIT
+            );
+        } else {
+            match uv_unix::adjust_open_file_limit() {
+                Ok(_) | Err(uv_unix::OpenFileLimitError::AlreadySufficient { .. }) => {}
+                // TODO(zanieb): When moving out of preview, consider changing this to a log instead
+                // of a warning because it's okay if we fail here.
+                Err(err) => warn_user!("{err}"),
+            }
         }
     }
 
@@ -1273,7 +1281,22 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, args.force, cache, printer).await
+            commands::cache_clean(
+                &args.package,
+                &args.packages_from,
+                args.keep_wheels,
+                &args.exclude,
+                args.show_inodes,
+                args.force,
+                environment
+                    .concurrency
+                    .cache_clean
+                    .or_else(|| NonZeroUsize::new(Concurrency::threads()))
+                    .unwrap_or(NonZeroUsize::MIN),
+                cache,
+                printer,
+            )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
@@ -1286,7 +1309,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
         }) => commands::cache_dir(&cache, printer),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
-        }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        }) => commands::cache_size(cache, args.human, printer, globals.preview).await,
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment)?;
@@ -1316,9 +1339,12 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.package,
                 args.all_packages,
                 args.out_dir,
+                args.sdist_dir,
+                args.wheel_dir,
                 args.sdist,
                 args.wheel,
                 args.list,
+                args.metadata_only,
                 args.build_logs,
                 args.gitignore,
                 args.force_pep517,
@@ -1360,6 +1386,16 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             let args = settings::VenvSettings::resolve(args, filesystem, environment)?;
             show_settings!(args);
 
+            if args.system_site_packages {
+                warn_user_once!(
+                    "The `--system-site-packages` flag makes system packages available at runtime, but uv will not consider them when installing packages into the environment, which may lead to conflicts"
+                );
+            }
+
+            if args.upgrade_deps && !args.seed {
+                warn_user_once!("The `--upgrade-deps` flag has no effect without `--seed`");
+            }
+
             // Check for conflicts between offline and refresh.
             globals
                 .network_settings
@@ -1412,7 +1448,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 &client_builder.subcommand(vec!["venv".to_owned()]),
                 uv_virtualenv::Prompt::from_args(prompt),
                 args.system_site_packages,
-                uv_virtualenv::Seed::from_args(args.seed),
+                uv_virtualenv::Seed::from_args(args.seed, args.without_pip),
                 on_existing,
                 args.settings.exclude_newer,
                 globals.concurrency,
@@ -1454,17 +1490,24 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                     target_version,
                     token,
                     dry_run,
+                    check,
                 }),
         }) => {
             commands::self_update(
                 target_version,
                 token,
                 dry_run,
+                check,
+                &cache,
                 printer,
                 client_builder.subcommand(vec!["self".to_owned(), "update".to_owned()]),
             )
             .await
         }
+        #[cfg(feature = "self-update")]
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Uninstall(SelfUninstallArgs { remove_cache, yes }),
+        }) => commands::self_uninstall(remove_cache, yes, &cache, printer).await,
         Commands::Self_(SelfNamespace {
             command:
                 SelfCommand::Version {
@@ -1918,6 +1961,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                     (&script).into(),
                     args.show_version,
                     args.resolve_links,
+                    args.output_format,
                     // TODO(zsol): is this the right thing to do here?
                     &client_builder.subcommand(vec!["python".to_owned(), "find".to_owned()]),
                     globals.python_preference,
@@ -1933,6 +1977,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                     args.request,
                     args.show_version,
                     args.resolve_links,
+                    args.output_format,
                     args.no_project,
                     args.system,
                     config_discovery,
@@ -1982,6 +2027,48 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             commands::python_dir(args.bin, printer)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Hash(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonHashSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::python_hash(
+                &args.request,
+                args.platform.as_deref(),
+                args.python_downloads_json_url.as_deref(),
+                &client_builder.subcommand(vec!["python".to_owned(), "hash".to_owned()]),
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Exec(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonExecSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::python_exec(
+                &args.request,
+                &args.command,
+                args.install_mirrors,
+                globals.python_preference,
+                globals.python_downloads,
+                &client_builder.subcommand(vec!["python".to_owned(), "exec".to_owned()]),
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::UpdateShell,
         }) => {
@@ -1989,16 +2076,6 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             Ok(ExitStatus::Success)
         }
         Commands::Publish(args) => {
-            if args.skip_existing {
-                bail!(
-                    "`uv publish` does not support `--skip-existing` because there is not a \
-                    reliable way to identify when an upload fails due to an existing \
-                    distribution. Instead, use `--check-url` to provide the URL to the simple \
-                    API for your index. uv will check the index for existing distributions before \
-                    attempting uploads."
-                );
-            }
-
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = PublishSettings::resolve(args, filesystem);
             show_settings!(args);
@@ -2009,6 +2086,12 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 password,
                 dry_run,
                 no_attestations,
+                attestation,
+                sign,
+                identity,
+                verify_metadata,
+                list,
+                skip_existing,
                 direct,
                 publish_url,
                 trusted_publishing,
@@ -2032,6 +2115,12 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 index_locations,
                 dry_run,
                 no_attestations,
+                attestation,
+                sign,
+                identity.as_deref(),
+                verify_metadata,
+                list,
+                skip_existing,
                 direct,
                 globals.preview,
                 &cache,
@@ -2264,11 +2353,15 @@ async fn run_project(
                 args.package,
                 args.kind,
                 args.bare,
+                args.template,
                 args.description,
                 args.no_description,
                 args.vcs,
                 args.build_backend,
                 args.no_readme,
+                args.readme_format,
+                args.license,
+                args.private,
                 args.author_from,
                 args.pin_python,
                 args.python,
@@ -2657,6 +2750,8 @@ async fn run_project(
                 args.active,
                 args.no_sync,
                 args.packages,
+                args.all,
+                args.keep,
                 args.dependency_type,
                 args.package,
                 args.python,
@@ -2750,6 +2845,8 @@ async fn run_project(
                 args.frozen,
                 args.universal,
                 args.format,
+                args.licenses,
+                args.licenses_filter,
                 args.depth,
                 args.prune,
                 args.package,
@@ -2805,6 +2902,7 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.include_annotations,
+                args.annotation_style,
                 args.include_header,
                 args.include_index_url,
                 args.include_find_links,
@@ -1,6 +1,10 @@
 use anstream::{eprint, print};
 use indicatif::ProgressDrawTarget;
 
+pub(crate) use pager::Pager;
+
+mod pager;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Printer {
     /// A printer that suppresses all output.
@@ -0,0 +1,185 @@
+//! Display output through a pager, e.g., `less`.
+
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use which::which;
+
+use uv_static::EnvVars;
+
+#[derive(Debug)]
+pub(crate) enum PagerKind {
+    Less,
+    More,
+    Other(String),
+}
+
+#[derive(Debug)]
+pub(crate) struct Pager {
+    kind: PagerKind,
+    args: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl PagerKind {
+    fn default_args(&self) -> Vec<String> {
+        match self {
+            Self::Less => vec!["-R".to_string()],
+            Self::More => vec![],
+            Self::Other(_) => vec![],
+        }
+    }
+}
+
+impl Display for PagerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Less => write!(f, "less"),
+            Self::More => write!(f, "more"),
+            Self::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl FromStr for Pager {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_ascii_whitespace();
+
+        // Empty string
+        let Some(first) = split.next() else {
+            return Err(());
+        };
+
+        match first {
+            "less" => Ok(Self {
+                kind: PagerKind::Less,
+                args: split.map(str::to_string).collect(),
+                path: None,
+            }),
+            "more" => Ok(Self {
+                kind: PagerKind::More,
+                args: split.map(str::to_string).collect(),
+                path: None,
+            }),
+            _ => Ok(Self {
+                kind: PagerKind::Other(first.to_string()),
+                args: split.map(str::to_string).collect(),
+                path: None,
+            }),
+        }
+    }
+}
+
+impl Pager {
+    /// Display `contents` using the pager.
+    pub(crate) fn spawn(self, heading: String, contents: impl Display) -> Result<()> {
+        use std::io::Write;
+
+        let command = self
+            .path
+            .as_ref()
+            .map(|path| path.as_os_str().to_os_string())
+            .unwrap_or(OsString::from(self.kind.to_string()));
+
+        let args = if self.args.is_empty() {
+            self.kind.default_args()
+        } else {
+            self.args
+        };
+
+        let mut child = std::process::Command::new(command)
+            .args(args)
+            .stdin(std::process::Stdio::piped())
+            .spawn()?;
+
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("Failed to take child process stdin"))?;
+
+        let contents = contents.to_string();
+        let writer = std::thread::spawn(move || {
+            let _ = write!(stdin, "{heading}\n\n");
+            let _ = stdin.write_all(contents.as_bytes());
+        });
+
+        drop(child.wait());
+        drop(writer.join());
+
+        Ok(())
+    }
+
+    /// Get a pager to use and its path, if available.
+    ///
+    /// Supports the `PAGER` environment variable, otherwise checks for `less` and `more` in the
+    /// search path.
+    pub(crate) fn try_from_env() -> Option<Self> {
+        Self::try_from_value(std::env::var_os(EnvVars::PAGER))
+    }
+
+    /// Get a pager to use from the given `PAGER` value, falling back to `less` and `more` in the
+    /// search path if unset or empty.
+    fn try_from_value(pager: Option<OsString>) -> Option<Self> {
+        if let Some(pager) = pager {
+            if !pager.is_empty() {
+                return Self::from_str(&pager.to_string_lossy()).ok();
+            }
+        }
+
+        if let Ok(less) = which("less") {
+            Some(Self {
+                kind: PagerKind::Less,
+                args: vec![],
+                path: Some(less),
+            })
+        } else if let Ok(more) = which("more") {
+            Some(Self {
+                kind: PagerKind::More,
+                args: vec![],
+                path: Some(more),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Whether the pager will render ANSI color codes.
+    pub(crate) fn supports_colors(&self) -> bool {
+        match self.kind {
+            // The `-R` flag is required for color support. We will provide it by default.
+            PagerKind::Less => self.args.is_empty() || self.args.iter().any(|arg| arg == "-R"),
+            PagerKind::More => false,
+            PagerKind::Other(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::{Pager, PagerKind};
+
+    #[test]
+    fn try_from_value() {
+        let pager = Pager::try_from_value(Some(OsString::from("less -FX"))).unwrap();
+        assert!(matches!(pager.kind, PagerKind::Less));
+        assert_eq!(pager.args, ["-FX"]);
+        assert!(!pager.supports_colors());
+
+        let pager = Pager::try_from_value(Some(OsString::from("bat --plain"))).unwrap();
+        assert!(matches!(pager.kind, PagerKind::Other(ref name) if name == "bat"));
+        assert_eq!(pager.args, ["--plain"]);
+        assert!(!pager.supports_colors());
+
+        // An empty value falls back to the search path.
+        if let Some(pager) = Pager::try_from_value(Some(OsString::new())) {
+            assert!(pager.path.is_some());
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use std::env::VarError;
+use std::ffi::OsString;
 use std::fmt;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
@@ -15,17 +16,17 @@ use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     AddArgs, AuditArgs, AuditOutputFormat, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs,
-    ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, MetadataArgs,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, TreeFormat, UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
-    VersionFormat,
+    ColorChoice, ExternalCommand, GlobalArgs, InitArgs, InitTemplate, ListFormat, LockArgs, Maybe,
+    MetadataArgs, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs,
+    PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonFindFormat,
+    PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs,
+    PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs,
+    ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, TreeFormat, UpgradeArgs, VenvArgs,
+    VersionArgs, VersionBumpSpec, VersionFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, CheckArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
-    ResolverInstallerArgs, ToolUpgradeArgs,
+    PythonExecArgs, PythonHashArgs, ReadmeFormat, ResolverInstallerArgs, ToolUpgradeArgs,
     options::{
         Flag, FlagSource, check_conflicts, flag, indexes_from_args, resolve_flag,
         resolve_flag_pair, resolver_installer_options, resolver_installer_options_with_indexes,
@@ -447,11 +448,15 @@ pub(crate) struct InitSettings {
     pub(crate) package: bool,
     pub(crate) kind: InitKind,
     pub(crate) bare: bool,
+    pub(crate) template: Option<PathBuf>,
     pub(crate) description: Option<String>,
     pub(crate) no_description: bool,
     pub(crate) vcs: Option<VersionControlSystem>,
     pub(crate) build_backend: Option<ProjectBuildBackend>,
     pub(crate) no_readme: bool,
+    pub(crate) readme_format: Option<ReadmeFormat>,
+    pub(crate) license: Option<String>,
+    pub(crate) private: bool,
     pub(crate) author_from: Option<AuthorFrom>,
     pub(crate) pin_python: bool,
     pub(crate) no_workspace: bool,
@@ -477,11 +482,15 @@ impl InitSettings {
             app,
             lib,
             script,
+            template,
             description,
             no_description,
             vcs,
             build_backend,
             no_readme,
+            readme_format,
+            license,
+            private,
             author_from,
             no_pin_python,
             pin_python,
@@ -490,6 +499,24 @@ impl InitSettings {
             ..
         } = args;
 
+        // The built-in templates are equivalent to the corresponding project kinds.
+        let (app, lib, script, build_backend, template) = match template {
+            Some(InitTemplate::Library) => (app, true, script, build_backend, None),
+            Some(InitTemplate::Application) => (true, lib, script, build_backend, None),
+            Some(InitTemplate::Script) => (app, lib, true, build_backend, None),
+            Some(InitTemplate::ExtensionModule) => (
+                app,
+                true,
+                script,
+                build_backend.or(Some(ProjectBuildBackend::Maturin)),
+                None,
+            ),
+            Some(InitTemplate::Directory(template)) => {
+                (app, lib, script, build_backend, Some(template))
+            }
+            None => (app, lib, script, build_backend, None),
+        };
+
         let bare = resolve_flag(bare, "bare", environment.init_bare).is_enabled();
 
         let filesystem_install_mirrors = filesystem
@@ -588,11 +615,15 @@ impl InitSettings {
             package,
             kind,
             bare,
+            template,
             description,
             no_description,
             vcs: vcs.or(bare.then_some(VersionControlSystem::None)),
             build_backend,
             no_readme,
+            readme_format,
+            license,
+            private,
             author_from,
             pin_python: flag(pin_python, no_pin_python, "pin-python")?.unwrap_or(!bare),
             no_workspace,
@@ -1357,6 +1388,8 @@ pub(crate) enum PythonListKinds {
     Downloads,
     /// Only list installed versions.
     Installed,
+    /// Only list versions installed in the managed Python directory.
+    Managed,
 }
 
 /// The resolved settings to use for a `tool run` invocation.
@@ -1389,6 +1422,7 @@ impl PythonListSettings {
             all_arches,
             only_installed,
             only_downloads,
+            only_managed,
             show_urls,
             output_format,
             python_downloads_json_url: python_downloads_json_url_arg,
@@ -1429,6 +1463,8 @@ impl PythonListSettings {
 
         let kinds = if only_installed {
             PythonListKinds::Installed
+        } else if only_managed {
+            PythonListKinds::Managed
         } else if only_downloads {
             PythonListKinds::Downloads
         } else {
@@ -1466,6 +1502,83 @@ impl PythonDirSettings {
     }
 }
 
+/// The resolved settings to use for a `python hash` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonHashSettings {
+    pub(crate) request: String,
+    pub(crate) platform: Option<String>,
+    pub(crate) python_downloads_json_url: Option<String>,
+}
+
+impl PythonHashSettings {
+    /// Resolve the [`PythonHashSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PythonHashArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PythonHashArgs {
+            request,
+            platform,
+            python_downloads_json_url,
+        } = args;
+
+        let python_downloads_json_url_option = filesystem
+            .map(FilesystemOptions::into_options)
+            .and_then(|options| options.install_mirrors.python_downloads_json_url);
+
+        let python_downloads_json_url = python_downloads_json_url
+            .or(environment.install_mirrors.python_downloads_json_url)
+            .or(python_downloads_json_url_option);
+
+        Self {
+            request,
+            platform,
+            python_downloads_json_url,
+        }
+    }
+}
+
+/// The resolved settings to use for a `python exec` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonExecSettings {
+    pub(crate) request: String,
+    pub(crate) command: Vec<OsString>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl PythonExecSettings {
+    /// Resolve the [`PythonExecSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PythonExecArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PythonExecArgs {
+            request,
+            command,
+            python_downloads_json_url,
+        } = args;
+
+        let filesystem_install_mirrors = filesystem
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        let install_mirrors = PythonInstallMirrors {
+            python_downloads_json_url,
+            ..Default::default()
+        }
+        .combine(environment.install_mirrors)
+        .combine(filesystem_install_mirrors);
+
+        Self {
+            request,
+            command,
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for a `python install` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PythonInstallSettings {
@@ -1672,6 +1785,7 @@ pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
     pub(crate) resolve_links: bool,
+    pub(crate) output_format: PythonFindFormat,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
     pub(crate) python_downloads_json_url: Option<String>,
@@ -1688,6 +1802,7 @@ impl PythonFindSettings {
             request,
             show_version,
             resolve_links,
+            output_format,
             no_project,
             system,
             no_system,
@@ -1716,6 +1831,7 @@ impl PythonFindSettings {
             request,
             show_version,
             resolve_links,
+            output_format,
             no_project,
             system: flag(system, no_system, "system")?.unwrap_or_default(),
             python_downloads_json_url,
@@ -2466,6 +2582,8 @@ pub(crate) struct RemoveSettings {
     pub(super) active: Option<bool>,
     pub(super) no_sync: bool,
     pub(super) packages: Vec<PackageName>,
+    pub(super) all: bool,
+    pub(super) keep: Vec<PackageName>,
     pub(super) dependency_type: DependencyType,
     pub(super) package: Option<PackageName>,
     pub(super) script: Option<PathBuf>,
@@ -2487,6 +2605,8 @@ impl RemoveSettings {
             dev,
             optional,
             packages,
+            all,
+            keep,
             group,
             no_sync,
             locked,
@@ -2543,6 +2663,8 @@ impl RemoveSettings {
             active: flag(active, no_active, "active")?,
             no_sync: no_sync.is_enabled(),
             packages,
+            all,
+            keep,
             dependency_type,
             package,
             script,
@@ -2658,6 +2780,8 @@ pub(crate) struct TreeSettings {
     pub(super) frozen: Option<FrozenSource>,
     pub(super) universal: bool,
     pub(super) format: TreeFormat,
+    pub(super) licenses: bool,
+    pub(super) licenses_filter: Vec<String>,
     pub(super) depth: u8,
     pub(super) prune: Vec<PackageName>,
     pub(super) package: Vec<PackageName>,
@@ -2685,6 +2809,8 @@ impl TreeSettings {
             tree,
             universal,
             format,
+            licenses,
+            licenses_filter,
             dev,
             only_dev,
             no_dev,
@@ -2741,6 +2867,8 @@ impl TreeSettings {
             frozen: resolve_frozen(frozen),
             universal,
             format,
+            licenses: licenses || !licenses_filter.is_empty(),
+            licenses_filter,
             depth: tree.depth,
             prune: tree.prune,
             package: tree.package,
@@ -2781,6 +2909,7 @@ pub(crate) struct ExportSettings {
     pub(super) lock_check: LockCheck,
     pub(super) frozen: Option<FrozenSource>,
     pub(super) include_annotations: bool,
+    pub(super) annotation_style: AnnotationStyle,
     pub(super) include_header: bool,
     pub(super) include_index_url: bool,
     pub(super) include_find_links: bool,
@@ -2817,6 +2946,7 @@ impl ExportSettings {
             all_groups,
             annotate,
             no_annotate,
+            annotation_style,
             header,
             no_header,
             emit_index_url,
@@ -2919,6 +3049,7 @@ impl ExportSettings {
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             include_annotations: flag(annotate, no_annotate, "annotate")?.unwrap_or(true),
+            annotation_style: annotation_style.unwrap_or_default(),
             include_header: flag(header, no_header, "header")?.unwrap_or(true),
             include_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url")?
                 .unwrap_or(false),
@@ -4066,9 +4197,12 @@ pub(crate) struct BuildSettings {
     pub(crate) package: Option<PackageName>,
     pub(crate) all_packages: bool,
     pub(crate) out_dir: Option<PathBuf>,
+    pub(crate) sdist_dir: Option<PathBuf>,
+    pub(crate) wheel_dir: Option<PathBuf>,
     pub(crate) sdist: bool,
     pub(crate) wheel: bool,
     pub(crate) list: bool,
+    pub(crate) metadata_only: bool,
     pub(crate) build_logs: bool,
     pub(crate) gitignore: bool,
     pub(crate) force_pep517: bool,
@@ -4092,11 +4226,14 @@ impl BuildSettings {
         let BuildArgs {
             src,
             out_dir,
+            sdist_dir,
+            wheel_dir,
             package,
             all_packages,
             sdist,
             wheel,
             list,
+            metadata_only,
             force_pep517,
             clear,
             build_constraints,
@@ -4136,9 +4273,12 @@ impl BuildSettings {
             package,
             all_packages,
             out_dir,
+            sdist_dir,
+            wheel_dir,
             sdist,
             wheel,
             list,
+            metadata_only,
             build_logs: flag(build_logs, no_build_logs, "build-logs")?.unwrap_or(true),
             force_pep517,
             clear,
@@ -4171,6 +4311,8 @@ impl BuildSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct VenvSettings {
     pub(crate) seed: bool,
+    pub(crate) without_pip: bool,
+    pub(crate) upgrade_deps: bool,
     pub(crate) allow_existing: bool,
     pub(crate) clear: bool,
     pub(crate) force: bool,
@@ -4197,6 +4339,8 @@ impl VenvSettings {
             system,
             no_system,
             seed,
+            without_pip,
+            upgrade_deps,
             allow_existing,
             clear,
             force,
@@ -4218,7 +4362,7 @@ impl VenvSettings {
         } = args;
 
         // Resolve flags from CLI and environment variables.
-        let seed = seed || environment.venv_seed.value == Some(true);
+        let seed = !without_pip && (seed || environment.venv_seed.value == Some(true));
         let (clear, no_clear) = resolve_flag_pair(
             clear,
             no_clear,
@@ -4236,8 +4380,33 @@ impl VenvSettings {
             None,
         );
 
+        let mut settings = PipSettings::combine(
+            PipOptions {
+                python: python.and_then(Maybe::into_option),
+                system: flag(system, no_system, "system")?,
+                index_strategy,
+                keyring_provider,
+                exclude_newer,
+                exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+                link_mode,
+                ..PipOptions::from(index_args)
+            },
+            filesystem,
+            environment,
+        );
+
+        // Upgrade the seed packages, if requested.
+        if upgrade_deps && seed {
+            let seed_upgrade = Upgrade::package(PackageName::from_str("pip")?)
+                .combine(Upgrade::package(PackageName::from_str("setuptools")?))
+                .combine(Upgrade::package(PackageName::from_str("wheel")?));
+            settings.upgrade = seed_upgrade.combine(settings.upgrade);
+        }
+
         Ok(Self {
             seed,
+            without_pip,
+            upgrade_deps,
             allow_existing,
             clear: clear.into(),
             force,
@@ -4249,21 +4418,7 @@ impl VenvSettings {
             relocatable: relocatable.into(),
             no_relocatable: no_relocatable.into(),
             refresh: Refresh::try_from(refresh)?,
-            settings: PipSettings::combine(
-                PipOptions {
-                    python: python.and_then(Maybe::into_option),
-                    system: flag(system, no_system, "system")?,
-                    index_strategy,
-                    keyring_provider,
-                    exclude_newer,
-                    exclude_newer_package: exclude_newer_package
-                        .map(ExcludeNewerPackage::from_iter),
-                    link_mode,
-                    ..PipOptions::from(index_args)
-                },
-                filesystem,
-                environment,
-            ),
+            settings,
         })
     }
 }
@@ -4998,6 +5153,12 @@ pub(crate) struct PublishSettings {
     pub(crate) index: Option<String>,
     pub(crate) dry_run: bool,
     pub(crate) no_attestations: bool,
+    pub(crate) attestation: bool,
+    pub(crate) sign: bool,
+    pub(crate) identity: Option<String>,
+    pub(crate) verify_metadata: bool,
+    pub(crate) list: bool,
+    pub(crate) skip_existing: bool,
     pub(crate) direct: bool,
 
     // Both CLI and configuration.
@@ -5019,6 +5180,12 @@ impl fmt::Debug for PublishSettings {
             .field("index", &self.index)
             .field("dry_run", &self.dry_run)
             .field("no_attestations", &self.no_attestations)
+            .field("attestation", &self.attestation)
+            .field("sign", &self.sign)
+            .field("identity", &self.identity)
+            .field("verify_metadata", &self.verify_metadata)
+            .field("list", &self.list)
+            .field("skip_existing", &self.skip_existing)
             .field("direct", &self.direct)
             .field("publish_url", &self.publish_url)
             .field("trusted_publishing", &self.trusted_publishing)
@@ -5064,6 +5231,12 @@ impl PublishSettings {
             password,
             dry_run: args.dry_run,
             no_attestations: args.no_attestations,
+            attestation: args.attestation,
+            sign: args.sign,
+            identity: args.identity,
+            verify_metadata: args.verify_metadata,
+            list: args.list,
+            skip_existing: args.skip_existing,
             direct: args.direct,
             publish_url: args
                 .publish_url
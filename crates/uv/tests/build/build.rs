@@ -133,6 +133,62 @@ fn build_basic() -> Result<()> {
         .child("project-0.1.0-py3-none-any.whl")
         .assert(predicate::path::is_file());
 
+    // `--output-dir` is an alias for `--out-dir`.
+    uv_snapshot!(&filters, context.build().arg("--output-dir").arg("output").current_dir(project.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Successfully built output/project-0.1.0.tar.gz
+    Successfully built output/project-0.1.0-py3-none-any.whl
+    ");
+
+    project
+        .child("output")
+        .child("project-0.1.0.tar.gz")
+        .assert(predicate::path::is_file());
+
+    // `--sdist-dir` and `--wheel-dir` take precedence over `--out-dir`.
+    uv_snapshot!(&filters, context.build().arg("--out-dir").arg("mixed").arg("--wheel-dir").arg("wheels").current_dir(project.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Successfully built mixed/project-0.1.0.tar.gz
+    Successfully built wheels/project-0.1.0-py3-none-any.whl
+    ");
+
+    project
+        .child("mixed")
+        .child("project-0.1.0.tar.gz")
+        .assert(predicate::path::is_file());
+    project
+        .child("mixed")
+        .child("project-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::missing());
+    project
+        .child("wheels")
+        .child("project-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+
+    uv_snapshot!(&filters, context.build().arg("--sdist-dir").arg("sdists").arg("--wheel-dir").arg("wheels").current_dir(project.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Successfully built sdists/project-0.1.0.tar.gz
+    Successfully built wheels/project-0.1.0-py3-none-any.whl
+    ");
+
+    project
+        .child("sdists")
+        .child("project-0.1.0.tar.gz")
+        .assert(predicate::path::is_file());
+    project
+        .child("sdists")
+        .child("project-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::missing());
+
     Ok(())
 }
 
@@ -2992,3 +3048,56 @@ fn build_no_gitignore() -> Result<()> {
 
     Ok(())
 }
+
+/// Test `uv build --metadata-only`.
+#[test]
+fn build_metadata_only() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+
+    uv_snapshot!(&context.filters(), context.build().arg("project").arg("--metadata-only").arg("--no-build-logs"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Preparing wheel metadata...
+    Successfully prepared project/dist/project-0.1.0.dist-info
+    ");
+
+    let metadata = fs_err::read_to_string(
+        project
+            .child("dist")
+            .child("project-0.1.0.dist-info")
+            .child("METADATA"),
+    )?;
+    assert!(metadata.contains("Requires-Dist: anyio==3.7.0"));
+    project
+        .child("dist")
+        .child("project-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::missing());
+
+    // `--metadata-only` can't be combined with `--sdist`.
+    uv_snapshot!(&context.filters(), context.build().arg("project").arg("--metadata-only").arg("--sdist"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--metadata-only' cannot be used with '--sdist'
+
+    Usage: uv build --metadata-only <SRC>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
@@ -242,6 +242,106 @@ fn clean_package_index() -> Result<()> {
     Ok(())
 }
 
+/// `cache clean --packages-from requirements.txt` should remove each named package.
+#[test]
+fn clean_packages_from() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio\niniconfig\ntyping-extensions")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let simple = context.cache_dir.child("simple-v24").child("pypi");
+    assert!(simple.child("iniconfig.rkyv").exists());
+    assert!(simple.child("typing-extensions.rkyv").exists());
+    assert!(simple.child("anyio.rkyv").exists());
+
+    let clean_txt = context.temp_dir.child("clean.txt");
+    clean_txt.write_str("iniconfig==2.0.0\ntyping-extensions")?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The file count varies by operating system, so we filter it out.
+            ("Removed \\d+ files?", "Removed [N] files"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.clean().arg("--packages-from").arg("clean.txt"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Removed [N] files ([SIZE])
+    ");
+
+    // Both named packages are removed, but not the others.
+    assert!(!simple.child("iniconfig.rkyv").exists());
+    assert!(!simple.child("typing-extensions.rkyv").exists());
+    assert!(simple.child("anyio.rkyv").exists());
+
+    Ok(())
+}
+
+/// `cache clean --packages-from -` should read a newline-delimited list of packages from stdin.
+#[test]
+#[expect(clippy::disallowed_types)]
+fn clean_packages_from_stdin() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheels = context.cache_dir.child("wheels-v6").child("pypi");
+    wheels.child("demo").child("a.txt").write_str("a")?;
+    wheels.child("other").child("b.txt").write_str("b")?;
+    wheels.child("keep").child("c.txt").write_str("c")?;
+
+    let clean_txt = context.temp_dir.child("clean.txt");
+    clean_txt.write_str("demo\nother\n")?;
+
+    uv_snapshot!(context.filters(), context.clean()
+        .arg("--packages-from")
+        .arg("-")
+        .stdin(std::fs::File::open(&clean_txt)?), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Removed 2 files ([SIZE])
+    ");
+
+    assert!(!wheels.child("demo").exists());
+    assert!(!wheels.child("other").exists());
+    assert!(wheels.child("keep").exists());
+
+    Ok(())
+}
+
+/// `cache clean --show-inodes` should report the number of files and directories removed.
+#[test]
+fn clean_show_inodes() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let package_entry = context
+        .cache_dir
+        .child("wheels-v6")
+        .child("pypi")
+        .child("demo");
+
+    package_entry.child("a.txt").write_str("a")?;
+    package_entry.child("sub").child("b.txt").write_str("b")?;
+
+    // Two files, plus the `demo` and `sub` directories.
+    uv_snapshot!(context.filters(), context.clean().arg("demo").arg("--show-inodes"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Removed 2 files ([SIZE])
+    Freed 4 inodes
+    ");
+
+    Ok(())
+}
+
 #[cfg(unix)]
 #[test]
 fn clean_package_does_not_follow_symlinks() -> Result<()> {
@@ -360,6 +360,8 @@ fn help_subcommand() {
       find          Search for a Python installation
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
+      hash          Show the expected SHA-256 hash of a Python download
+      exec          Run a command with a specific Python version
       uninstall     Uninstall Python versions
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
@@ -808,6 +810,8 @@ fn help_flag_subcommand() {
       find          Search for a Python installation
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
+      hash          Show the expected SHA-256 hash of a Python download
+      exec          Run a command with a specific Python version
       uninstall     Uninstall Python versions
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
@@ -1009,11 +1013,34 @@ fn help_unknown_subsubcommand() {
         find
         pin
         dir
+        hash
+        exec
         uninstall
         update-shell
     ");
 }
 
+#[test]
+fn help_subcommand_alias() -> anyhow::Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    // An alias resolves to the same help as the canonical name, including hidden aliases.
+    for (alias, name) in [
+        (["tool", "ls"], ["tool", "list"]),
+        (["cache", "clear"], ["cache", "clean"]),
+    ] {
+        let alias_output = context.help().args(alias).output()?;
+        let name_output = context.help().args(name).output()?;
+        assert!(alias_output.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&alias_output.stdout),
+            String::from_utf8_lossy(&name_output.stdout)
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 fn help_with_global_option() {
     let context = uv_test::test_context_with_versions!(&[]);
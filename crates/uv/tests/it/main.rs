@@ -18,6 +18,9 @@ mod help;
 
 mod network;
 
+#[cfg(unix)]
+mod resource_limits;
+
 #[cfg(feature = "test-pypi")]
 mod publish;
 
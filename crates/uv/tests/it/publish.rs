@@ -162,22 +162,6 @@ fn no_credentials() {
     );
 }
 
-/// Hint people that it's not `--skip-existing` but `--check-url`.
-#[test]
-fn skip_existing_redirect() {
-    let context = uv_test::test_context!("3.12");
-
-    uv_snapshot!(context.filters(), context.publish()
-        .arg("--skip-existing")
-        .arg("--publish-url")
-        .arg("https://test.pypi.org/legacy/"), @"
-    exit_code: 2 (failure)
-    ----- stderr -----
-    error: `uv publish` does not support `--skip-existing` because there is not a reliable way to identify when an upload fails due to an existing distribution. Instead, use `--check-url` to provide the URL to the simple API for your index. uv will check the index for existing distributions before attempting uploads.
-    "
-    );
-}
-
 #[test]
 fn dubious_filenames() {
     let context = uv_test::test_context!("3.12");
@@ -189,6 +173,17 @@ fn dubious_filenames() {
         .child("not-sdist-1-2-3-asdf.zip")
         .touch()
         .unwrap();
+    context
+        .temp_dir
+        .child("legacy-1.0.0-py3.12.egg")
+        .touch()
+        .unwrap();
+    context
+        .temp_dir
+        .child("legacy-1.0.0.win-amd64.exe")
+        .touch()
+        .unwrap();
+    context.temp_dir.child("notes.txt").touch().unwrap();
 
     uv_snapshot!(context.filters(), context.publish()
         .current_dir(current_dir().unwrap())
@@ -202,6 +197,8 @@ fn dubious_filenames() {
     exit_code: 2 (failure)
     ----- stderr -----
     warning: Skipping file that looks like a distribution, but is not a valid distribution filename: `[TEMP_DIR]/data.tar.gz`
+    warning: Skipping legacy `.egg` distribution, only wheels and source distributions can be uploaded: `[TEMP_DIR]/legacy-1.0.0-py3.12.egg`
+    warning: Skipping legacy `.exe` distribution, only wheels and source distributions can be uploaded: `[TEMP_DIR]/legacy-1.0.0.win-amd64.exe`
     warning: Skipping file that looks like a distribution, but is not a valid distribution filename: `[TEMP_DIR]/not-a-wheel.whl`
     warning: Skipping file that looks like a distribution, but is not a valid distribution filename: `[TEMP_DIR]/not-sdist-1-2-3-asdf.zip`
     error: No files found to publish
@@ -777,6 +774,119 @@ async fn direct_publish_redacts_presigned_upload_url() {
     );
 }
 
+/// With `--skip-existing`, a file that the index rejects as already existing is skipped.
+#[tokio::test]
+async fn skip_existing() {
+    let context = uv_test::test_context!("3.12");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/upload"))
+        .respond_with(ResponseTemplate::new(400).set_body_string(
+            "400 File already exists ('ok-1.0.0-py3-none-any.whl', with blake2_256 hash '0123'). \
+            See https://pypi.org/help/#file-name-reuse for more information.",
+        ))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--skip-existing")
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg(dummy_wheel()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Hashing ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    File already exists, skipping
+    ");
+
+    // Without `--skip-existing`, the upload fails.
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg(dummy_wheel()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Hashing ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    error: Failed to publish `[WORKSPACE]/test/links/ok-1.0.0-py3-none-any.whl` to http://[LOCALHOST]/upload
+      Caused by: Server returned status code 400 Bad Request. Server says: 400 File already exists ('ok-1.0.0-py3-none-any.whl', with blake2_256 hash '0123'). See https://pypi.org/help/#file-name-reuse for more information.
+    ");
+}
+
+/// With `--skip-existing`, `--direct` skips a file that the index rejects as already existing
+/// when finalizing the upload.
+#[tokio::test]
+async fn skip_existing_direct() {
+    let context = uv_test::test_context!("3.12");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/upload/reserve"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "upload_url": null,
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/upload/finalize"))
+        .respond_with(ResponseTemplate::new(409).set_body_string("File already exists"))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--preview-features")
+        .arg("direct-publish")
+        .arg("--direct")
+        .arg("--skip-existing")
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg(dummy_wheel()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Hashing ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    File already exists, skipping
+    ");
+
+    // Without `--skip-existing`, the upload fails.
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--preview-features")
+        .arg("direct-publish")
+        .arg("--direct")
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg(dummy_wheel()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Hashing ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    error: Failed to finalize upload for `[WORKSPACE]/test/links/ok-1.0.0-py3-none-any.whl`
+      Caused by: Server returned status code 409 Conflict. Server says: File already exists
+    ");
+}
+
 /// PyPI returns `application/json` errors with a `code` field.
 #[tokio::test]
 async fn upload_error_pypi_json() {
@@ -845,6 +955,69 @@ async fn upload_error_problem_details() {
     );
 }
 
+/// `--repository-url` is accepted as an alias of `--publish-url`, as in twine.
+#[tokio::test]
+async fn repository_url() {
+    let context = uv_test::test_context!("3.12");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/upload"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--repository-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg(dummy_wheel()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Hashing ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "
+    );
+
+    // The upload endpoint and a named index are mutually exclusive.
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--repository-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg("--index")
+        .arg("internal")
+        .arg(dummy_wheel()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--publish-url <PUBLISH_URL>' cannot be used with '--index <INDEX>'
+
+    Usage: uv publish --publish-url <PUBLISH_URL> <FILES>...
+
+    For more information, try '--help'.
+    "
+    );
+}
+
+/// `--list` validates and displays the files without making any requests.
+#[test]
+fn list() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--list")
+        .arg("--offline")
+        .arg(dummy_wheel()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    ok-1.0.0-py3-none-any.whl  [SIZE]  79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f
+    "
+    );
+}
+
 /// Test that `--dry-run` checks all files and reports all errors instead of
 /// stopping at the first failure.
 #[test]
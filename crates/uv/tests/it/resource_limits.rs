@@ -0,0 +1,36 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+use uv_static::EnvVars;
+
+/// With `UV_NO_RLIMIT`, the open file limit is not adjusted.
+#[test]
+fn no_rlimit() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    context
+        .command()
+        .arg("--preview-features")
+        .arg("adjust-ulimit")
+        .arg("-v")
+        .arg("cache")
+        .arg("dir")
+        .env(EnvVars::UV_NO_RLIMIT, "1")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Skipping open file limit adjustment due to `UV_NO_RLIMIT`",
+        ));
+
+    context
+        .command()
+        .arg("--preview-features")
+        .arg("adjust-ulimit")
+        .arg("-v")
+        .arg("cache")
+        .arg("dir")
+        .env(EnvVars::UV_NO_RLIMIT, "0")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Skipping open file limit adjustment").not());
+}
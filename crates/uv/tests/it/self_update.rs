@@ -1,11 +1,13 @@
 use std::{path::PathBuf, process::Command};
 
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use axoupdater::{
     ReleaseSourceType,
     test::helpers::{RuntestArgs, perform_runtest},
 };
+use predicates::prelude::predicate;
 use regex::escape;
 use serde_json::json;
 use wiremock::matchers::{method, path};
@@ -80,6 +82,114 @@ fn self_update_offline_extra_quiet() {
     ");
 }
 
+/// `uv self uninstall` should refuse to remove anything without `--yes` in a non-interactive
+/// session.
+#[test]
+fn self_uninstall_requires_confirmation() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.self_uninstall(), @r"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    error: Refusing to uninstall uv without confirmation in a non-interactive session (use `--yes` to proceed)
+    ");
+}
+
+/// `uv self uninstall --yes` should remove installed tools and managed Python installations,
+/// including their executables, along with the uv executable and its install receipt.
+#[cfg(unix)]
+#[test]
+fn self_uninstall() -> Result<()> {
+    let context = uv_test::test_context!("3.13").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let python_dir = context.temp_dir.child("managed");
+
+    // Install a tool, which adds an executable to the bin directory.
+    context
+        .tool_install()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("basic-app")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+    bin_dir.child("basic-app").assert(predicate::path::exists());
+
+    // Create a managed Python installation, which is linked into the bin directory.
+    let executable = python_dir.child("cpython-3.12.0-linux-x86_64-gnu/bin/python3.12");
+    executable.touch()?;
+    fs_err::os::unix::fs::symlink(&executable, bin_dir.child("python3.12"))?;
+
+    // Copy uv into a separate install directory, so that the test binary is left in place.
+    let install_dir = context.temp_dir.child("install");
+    install_dir.create_dir_all()?;
+    let uv = install_dir.child("uv");
+    fs_err::copy(get_bin!(), &uv)?;
+
+    // Write an install receipt for the copied executable.
+    let receipt_dir = context.temp_dir.child("receipt");
+    receipt_dir
+        .child("uv-receipt.json")
+        .write_str(&serde_json::to_string_pretty(&json!({
+            "install_prefix": install_dir.path(),
+            "binaries": ["uv"],
+            "cdylibs": [],
+            "source": {
+                "release_type": "github",
+                "owner": "astral-sh",
+                "name": "uv",
+                "app_name": "uv",
+            },
+            "version": env!("CARGO_PKG_VERSION"),
+            "provider": {
+                "source": "cargo-dist",
+                "version": "0.31.0",
+            },
+            "modify_path": true,
+        }))?)?;
+
+    let mut command = Command::new(uv.path());
+    command.arg("self").arg("uninstall").arg("--yes");
+    context.add_shared_options(&mut command, false);
+    command
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, python_dir.as_os_str())
+        .env(EnvVars::UV_PYTHON_BIN_DIR, bin_dir.as_os_str())
+        .env("AXOUPDATER_CONFIG_PATH", receipt_dir.as_os_str());
+
+    uv_snapshot!(context.filters(), command, @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Searching for Python installations
+    Uninstalled Python 3.12.0 in [TIME]
+     - cpython-3.12.0-linux-x86_64-gnu (python3.12)
+    Removed managed
+    Uninstalled 1 executable: basic-app
+    Removed install/uv
+    Removed receipt/uv-receipt.json
+    ");
+
+    // Nothing should be left behind.
+    bin_dir
+        .child("basic-app")
+        .assert(predicate::path::missing());
+    bin_dir
+        .child("python3.12")
+        .assert(predicate::path::missing());
+    tool_dir.assert(predicate::path::missing());
+    python_dir.assert(predicate::path::missing());
+    uv.assert(predicate::path::missing());
+    receipt_dir
+        .child("uv-receipt.json")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
 /// Set up a fake receipt and a mock update metadata endpoint to allow
 /// simulating an update with `--dry-run`.
 async fn setup_mock_update(
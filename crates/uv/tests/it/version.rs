@@ -2286,6 +2286,11 @@ fn self_version_json() -> Result<()> {
                 r#"commits_since_last_tag": [COUNT]"#,
             ),
             (r#"target_triple": ".*""#, r#"target_triple": "[TARGET]""#),
+            (
+                r#"rustc_version": (".*"|null)"#,
+                r#"rustc_version": "[RUSTC]""#,
+            ),
+            (r#"(?s)"features": \[.*?\]"#, r#""features": [FEATURES]"#),
         ])
         .collect::<Vec<_>>();
 
@@ -2304,7 +2309,9 @@ fn self_version_json() -> Result<()> {
             "last_tag": "[TAG]",
             "commits_since_last_tag": [COUNT]
           },
-          "target_triple": "[TARGET]"
+          "target_triple": "[TARGET]",
+          "rustc_version": "[RUSTC]",
+          "features": [FEATURES]
         }
         "#);
     } else {
@@ -2316,7 +2323,9 @@ fn self_version_json() -> Result<()> {
         "package_name": "uv",
         "version": "[VERSION]",
         "commit_info": null,
-        "target_triple": "[TARGET]"
+        "target_triple": "[TARGET]",
+        "rustc_version": "[RUSTC]",
+        "features": [FEATURES]
       }
       "#);
     }
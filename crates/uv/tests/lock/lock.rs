@@ -15063,6 +15063,77 @@ fn lock_no_sources_package() -> Result<()> {
     Ok(())
 }
 
+/// Test that `--no-sources-package` entries with extras only apply when those extras are requested.
+#[test]
+fn lock_no_sources_package_extra() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        foo = []
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { path = "./child" }
+        "#,
+    )?;
+
+    // Without the `foo` extra, the source is used.
+    uv_snapshot!(context.filters(), context.lock().arg("--offline").arg("--no-sources-package").arg("child[foo]"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child[foo]"]
+
+        [tool.uv.sources]
+        child = { path = "./child" }
+        "#,
+    )?;
+
+    // With the `foo` extra, the source is ignored, so `child` is resolved from the (offline) index.
+    uv_snapshot!(context.filters(), context.lock().arg("--offline").arg("--no-sources-package").arg("child[foo]"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there are no versions of child[foo] and your project depends on child[foo], we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    // An entry scoped to a different extra does not apply.
+    uv_snapshot!(context.filters(), context.lock().arg("--offline").arg("--no-sources-package").arg("child[bar]"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Test that `--no-sources-package` works with multiple packages.
 #[cfg(all(feature = "test-universal", feature = "test-git"))]
 #[test]
@@ -4051,6 +4051,73 @@ fn remove_registry() -> Result<()> {
     Ok(())
 }
 
+/// Remove all requirements from a dependency table, keeping some.
+#[test]
+fn remove_all_keep() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig", "typing-extensions"]
+
+        [dependency-groups]
+        lint = ["ruff", "flake8"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.remove().arg("--all").arg("--keep").arg("iniconfig").arg("--frozen"), @"
+    exit_code: 0 (success)
+    ");
+
+    uv_snapshot!(context.filters(), context.remove().arg("--all").arg("--group").arg("lint").arg("--frozen"), @"
+    exit_code: 0 (success)
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+         "iniconfig",
+        ]
+
+        [dependency-groups]
+        lint = []
+        "#
+        );
+    });
+
+    // Removing all dependencies from an empty table is a no-op.
+    uv_snapshot!(context.filters(), context.remove().arg("--all").arg("--group").arg("lint").arg("--frozen"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    No dependencies to remove
+    ");
+
+    // Packages can't be provided alongside `--all`.
+    uv_snapshot!(context.filters(), context.remove().arg("--all").arg("iniconfig"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--all' cannot be used with '[PACKAGES]...'
+
+    Usage: uv remove --cache-dir [CACHE_DIR] --all --exclude-newer <EXCLUDE_NEWER> [PACKAGES]...
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn add_preserves_indentation_in_pyproject_toml() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -4193,6 +4260,18 @@ fn add_frozen() -> Result<()> {
     assert!(!context.temp_dir.join("uv.lock").exists());
     assert!(!context.venv.exists());
 
+    // If a lockfile exists, warn that it was not updated.
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--frozen"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: The lockfile at `uv.lock` was not updated and may be out of date; run `uv lock` to update it
+    ");
+
+    assert!(!context.venv.exists());
+
     Ok(())
 }
 
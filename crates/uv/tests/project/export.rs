@@ -158,6 +158,42 @@ fn requirements_txt_export_no_header() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn requirements_txt_annotation_style_line() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-header").arg("--no-hashes").arg("--annotation-style").arg("line"), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    -e .
+    anyio==3.7.0              # via project
+    idna==3.6                 # via anyio
+    sniffio==1.3.1            # via anyio
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[cfg(feature = "test-universal")]
 #[test]
 fn requirements_txt_dependency_extra() -> Result<()> {
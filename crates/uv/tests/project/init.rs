@@ -419,6 +419,200 @@ fn init_library() -> Result<()> {
     Ok(())
 }
 
+/// Mark a project as private with `--private`.
+#[test]
+fn init_private() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("--private").arg("--author-from").arg("none").arg("foo"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    ");
+
+    let pyproject = fs_err::read_to_string(context.temp_dir.child("foo").child("pyproject.toml"))?;
+    assert_snapshot!(
+        pyproject, @r#"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    description = "Add your description here"
+    readme = "README.md"
+    requires-python = ">=3.12"
+    dependencies = []
+    # The `Private :: Do Not Upload` classifier prevents uploads to PyPI.
+    classifiers = ["Private :: Do Not Upload"]
+    "#
+    );
+
+    Ok(())
+}
+
+/// Create a `LICENSE` file with `--license`.
+#[test]
+fn init_license() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("--license").arg("mit").arg("--author-from").arg("none").arg("foo"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    ");
+
+    let license = fs_err::read_to_string(context.temp_dir.child("foo").child("LICENSE"))?;
+    let mut lines = license.lines();
+    assert_eq!(lines.next(), Some("MIT License"));
+    assert_eq!(lines.next(), Some(""));
+    assert_eq!(
+        lines.next(),
+        Some(
+            format!(
+                "Copyright (c) {} <copyright holders>",
+                jiff::Zoned::now().year()
+            )
+            .as_str()
+        )
+    );
+
+    // The license is declared in the `pyproject.toml`.
+    let pyproject = context.read("foo/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.12"
+        dependencies = []
+        license = "MIT"
+        "#
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.init().arg("--license").arg("GPL-3.0+").arg("bar"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: Unsupported license `GPL-3.0+`; expected one of: 0BSD, AGPL-3.0-only, AGPL-3.0-or-later, Apache-2.0, Artistic-2.0, BSD-2-Clause, BSD-3-Clause, BSL-1.0, CC0-1.0, EPL-2.0, EUPL-1.2, GPL-2.0-only, GPL-2.0-or-later, GPL-3.0-only, GPL-3.0-or-later, ISC, LGPL-2.1-only, LGPL-2.1-or-later, LGPL-3.0-only, LGPL-3.0-or-later, MIT, MIT-0, MPL-2.0, Unlicense, Zlib
+    ");
+
+    context
+        .temp_dir
+        .child("bar")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Initialize a project from a template directory.
+#[test]
+fn init_template() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let template = context.temp_dir.child("template");
+    template.child("uv-template.toml").write_str(indoc! {r#"
+        [variables]
+        greeting = "Hello"
+    "#})?;
+    template.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "{{ name }}"
+        version = "0.1.0"
+        description = "{{ description }}"
+        requires-python = "{{ requires_python }}"
+        dependencies = []
+    "#})?;
+    template
+        .child("src")
+        .child("{{ module }}")
+        .child("__init__.py")
+        .write_str(r#"print("{{ greeting }} from {{module}}!")"#)?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("template").arg("foo-bar"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    ");
+
+    let child = context.temp_dir.child("foo-bar");
+    let pyproject = fs_err::read_to_string(child.join("pyproject.toml"))?;
+    assert_snapshot!(
+        pyproject, @r#"
+    [project]
+    name = "foo-bar"
+    version = "0.1.0"
+    description = "Add your description here"
+    requires-python = ">=3.12"
+    dependencies = []
+    "#
+    );
+    let init = fs_err::read_to_string(child.join("src").join("foo_bar").join("__init__.py"))?;
+    assert_snapshot!(init, @r#"print("Hello from foo_bar!")"#);
+    child
+        .child("uv-template.toml")
+        .assert(predicate::path::missing());
+
+    // Unknown placeholders are left untouched.
+    template
+        .child(".github")
+        .child("workflows")
+        .child("publish.yml")
+        .write_str(indoc! {r"
+            name: Publish {{ name }}
+            env:
+              TOKEN: ${{ secrets.TOKEN }}
+        "})?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("template").arg("baz"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Initialized project `baz` at `[TEMP_DIR]/baz`
+    ");
+
+    let workflow = fs_err::read_to_string(
+        context
+            .temp_dir
+            .join("baz")
+            .join(".github")
+            .join("workflows")
+            .join("publish.yml"),
+    )?;
+    assert_snapshot!(workflow, @"
+    name: Publish baz
+    env:
+      TOKEN: ${{ secrets.TOKEN }}
+    ");
+
+    // A template directory requires a manifest.
+    fs_err::remove_file(template.child("uv-template.toml"))?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("template").arg("qux"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `template` is not a project template (missing `uv-template.toml`)
+    ");
+
+    // The built-in templates correspond to the project kinds.
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("library").arg("lib"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Initialized project `lib` at `[TEMP_DIR]/lib`
+    ");
+
+    context
+        .temp_dir
+        .child("lib")
+        .child("src")
+        .child("lib")
+        .child("py.typed")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
 /// Test that the uv build backend is used with `uv init --package`.
 #[test]
 fn init_package() -> Result<()> {
@@ -1108,6 +1302,48 @@ fn init_no_readme() {
     });
 }
 
+#[test]
+fn init_readme_format() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--readme-format").arg("rst"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    ");
+
+    let pyproject = context.read("foo/pyproject.toml");
+    assert!(context.temp_dir.join("foo/README.rst").is_file());
+    let _ = fs_err::read_to_string(context.temp_dir.join("foo/README.md")).unwrap_err();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.rst"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        );
+    });
+
+    // `--readme-format` conflicts with `--no-readme`.
+    uv_snapshot!(context.filters(), context.init().arg("bar").arg("--readme-format").arg("txt").arg("--no-readme"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--readme-format <README_FORMAT>' cannot be used with '--no-readme'
+
+    Usage: uv init --cache-dir [CACHE_DIR] --readme-format <README_FORMAT> <PATH>
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn init_no_pin_python() {
     let context = uv_test::test_context!("3.12");
@@ -2,6 +2,7 @@ use std::process::Command;
 
 use anyhow::{Context, Result, bail};
 use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 use indoc::{formatdoc, indoc};
 use insta::{assert_json_snapshot, assert_snapshot};
@@ -4510,6 +4511,128 @@ fn show_sizes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn licenses() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "iniconfig"]
+
+        [tool.uv]
+        package = false
+        "#,
+    )?;
+
+    context.temp_dir.child("uv.lock").write_str(
+        r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+            { name = "iniconfig" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        "#,
+    )?;
+
+    // Install all packages but `iniconfig`, reading the license from the `License-Expression`,
+    // `License`, and classifier metadata fields, respectively.
+    let site_packages = ChildPath::new(context.site_packages());
+    for (name, version, license) in [
+        ("anyio", "4.3.0", "License-Expression: MIT"),
+        ("idna", "3.6", "License: BSD-3-Clause"),
+        (
+            "sniffio",
+            "1.3.1",
+            "Classifier: License :: OSI Approved :: MIT",
+        ),
+    ] {
+        site_packages
+            .child(format!("{name}-{version}.dist-info"))
+            .child("METADATA")
+            .write_str(&format!(
+                "Metadata-Version: 2.4\nName: {name}\nVersion: {version}\n{license}\n"
+            ))?;
+    }
+
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--licenses"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    project v0.1.0
+    ├── anyio v4.3.0 (license: MIT)
+    │   ├── idna v3.6 (license: BSD-3-Clause)
+    │   └── sniffio v1.3.1 (license: MIT)
+    └── iniconfig v2.0.0
+
+    ----- stderr -----
+    warning: Licenses are unknown for packages that are not installed in the project environment: `iniconfig`; run `uv sync` to install them
+    ");
+
+    // Only packages with a matching license are displayed, retaining the dependencies between
+    // them.
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--licenses-filter").arg("mit,Apache-2.0"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    anyio v4.3.0 (license: MIT)
+    └── sniffio v1.3.1 (license: MIT)
+
+    ----- stderr -----
+    warning: Licenses are unknown for packages that are not installed in the project environment: `iniconfig`; run `uv sync` to install them
+    ");
+
+    // A package whose dependents don't match is displayed as a root.
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--licenses-filter").arg("BSD-3-Clause"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    idna v3.6 (license: BSD-3-Clause)
+
+    ----- stderr -----
+    warning: Licenses are unknown for packages that are not installed in the project environment: `iniconfig`; run `uv sync` to install them
+    ");
+
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--licenses-filter").arg("GPL-3.0"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: Licenses are unknown for packages that are not installed in the project environment: `iniconfig`; run `uv sync` to install them
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn workspace_circular_dependencies() -> Result<()> {
     let context = uv_test::test_context!("3.12");
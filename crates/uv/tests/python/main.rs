@@ -2,9 +2,14 @@
 
 mod python_dir;
 
+#[cfg(feature = "test-python")]
+mod python_exec;
+
 #[cfg(feature = "test-python")]
 mod python_find;
 
+mod python_hash;
+
 #[cfg(feature = "test-python-managed")]
 mod python_install;
 
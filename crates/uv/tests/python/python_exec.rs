@@ -0,0 +1,39 @@
+use uv_test::uv_snapshot;
+
+#[test]
+fn python_exec() {
+    let context = uv_test::test_context_with_versions!(&["3.11", "3.12"]);
+
+    // Run `python` with the requested version on the `PATH`.
+    uv_snapshot!(context.filters(), context.python_exec()
+        .arg("3.12")
+        .arg("--")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; print('%d.%d' % sys.version_info[:2])"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    3.12
+    ");
+
+    uv_snapshot!(context.filters(), context.python_exec()
+        .arg("3.11")
+        .arg("--")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; print('%d.%d' % sys.version_info[:2])"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    3.11
+    ");
+
+    // The exit code of the command is propagated.
+    uv_snapshot!(context.filters(), context.python_exec()
+        .arg("3.12")
+        .arg("--")
+        .arg("python")
+        .arg("-c")
+        .arg("raise SystemExit(3)"), @"
+    exit_code: 3 (failure)
+    ");
+}
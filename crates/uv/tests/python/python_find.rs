@@ -954,6 +954,31 @@ fn python_find_show_version() {
     ");
 }
 
+#[test]
+fn python_find_output_format_json() {
+    let context = uv_test::test_context_with_versions!(&["3.11", "3.12"]).with_filter((
+        r#""arch":"[^"]+""#.to_string(),
+        r#""arch":"[ARCH]""#.to_string(),
+    ));
+
+    uv_snapshot!(context.filters(), context.python_find().arg("--output-format").arg("json").arg("3.12"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    {"path":"[PYTHON-3.12]","version":"3.12.[X]","implementation":"cpython","arch":"[ARCH]","managed":false}
+    "#);
+
+    // The version is included in the JSON output already
+    uv_snapshot!(context.filters(), context.python_find().arg("--output-format").arg("json").arg("--show-version"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--output-format <OUTPUT_FORMAT>' cannot be used with '--show-version'
+
+    Usage: uv python find --cache-dir [CACHE_DIR] --output-format <OUTPUT_FORMAT> [REQUEST]
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn python_find_path() {
     let context = uv_test::test_context_with_versions!(&[]).with_filtered_not_executable();
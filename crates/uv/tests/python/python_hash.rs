@@ -0,0 +1,127 @@
+use anyhow::Result;
+use uv_test::uv_snapshot;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[tokio::test]
+async fn python_hash() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+    let server = MockServer::start().await;
+
+    let remote_json = r#"
+    {
+        "cpython-3.14.0-darwin-aarch64-none": {
+            "name": "cpython",
+            "arch": {
+                "family": "aarch64",
+                "variant": null
+            },
+            "os": "darwin",
+            "libc": "none",
+            "major": 3,
+            "minor": 14,
+            "patch": 0,
+            "prerelease": "",
+            "url": "https://custom.com/cpython-3.14.0-darwin-aarch64-none.tar.gz",
+            "sha256": "c3223d5924a0ed0ef5958a750377c362d0957587f896c0f6c635ae4b39e0f337",
+            "variant": null,
+            "build": "20251028"
+        },
+        "cpython-3.14.0-linux-x86_64-gnu": {
+            "name": "cpython",
+            "arch": {
+                "family": "x86_64",
+                "variant": null
+            },
+            "os": "linux",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 14,
+            "patch": 0,
+            "prerelease": "",
+            "url": "https://custom.com/cpython-3.14.0-linux-x86_64-gnu.tar.gz",
+            "sha256": "6ae8fa44cb2edf4ab49cff1820b53c40c10349c0f39e11b8cd76ce7f3e7e1def",
+            "variant": null,
+            "build": "20251028"
+        },
+        "cpython-3.14.0-linux-aarch64-gnu": {
+            "name": "cpython",
+            "arch": {
+                "family": "aarch64",
+                "variant": null
+            },
+            "os": "linux",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 14,
+            "patch": 0,
+            "prerelease": "",
+            "url": "https://custom.com/cpython-3.14.0-linux-aarch64-gnu.tar.gz",
+            "sha256": null,
+            "variant": null,
+            "build": "20251028"
+        }
+    }
+    "#;
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(remote_json, "application/json"))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context
+        .python_hash()
+        .arg("3.14")
+        .arg("--platform").arg("macos-aarch64-none")
+        .arg("--python-downloads-json-url").arg(server.uri()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    c3223d5924a0ed0ef5958a750377c362d0957587f896c0f6c635ae4b39e0f337
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .python_hash()
+        .arg("cpython@3.14.0")
+        .arg("--platform").arg("linux-x86_64-gnu")
+        .arg("--python-downloads-json-url").arg(server.uri()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    6ae8fa44cb2edf4ab49cff1820b53c40c10349c0f39e11b8cd76ce7f3e7e1def
+    ");
+
+    // The download does not include a hash
+    uv_snapshot!(context.filters(), context
+        .python_hash()
+        .arg("3.14")
+        .arg("--platform").arg("linux-aarch64-gnu")
+        .arg("--python-downloads-json-url").arg(server.uri()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: No hash is available for the Python download `cpython-3.14.0-linux-aarch64-gnu`
+    ");
+
+    // There is no download for the platform
+    uv_snapshot!(context.filters(), context
+        .python_hash()
+        .arg("3.14")
+        .arg("--platform").arg("windows-x86_64-none")
+        .arg("--python-downloads-json-url").arg(server.uri()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: No download found for request: cpython-3.14-windows-x86_64-none
+    ");
+
+    // The request does not refer to a download
+    uv_snapshot!(context.filters(), context
+        .python_hash()
+        .arg("/usr/bin/python3")
+        .arg("--python-downloads-json-url").arg(server.uri()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: No Python download is available for the request `/usr/bin/python3`
+    ");
+
+    Ok(())
+}
@@ -1492,6 +1492,15 @@ fn python_install_invalid_request() {
     ----- stderr -----
     error: No download found for request: cpython-3.8.0-[PLATFORM]
     ");
+
+    // Provide a mirror that is not a URL
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--mirror-url").arg("not a url"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: invalid value 'not a url' for '--mirror <MIRROR>': relative URL without a base
+
+    For more information, try '--help'.
+    ");
 }
 
 #[test]
@@ -467,6 +467,16 @@ fn python_list_managed_symlinks() {
     cpython-3.10.[LATEST]-[PLATFORM]    [BIN]/[PYTHON] -> managed/cpython-3.10-[PLATFORM]/[INSTALL-BIN]/[PYTHON]
     cpython-3.10.[LATEST]-[PLATFORM]    managed/cpython-3.10-[PLATFORM]/[INSTALL-BIN]/[PYTHON]
     ");
+
+    // With `--only-managed`, only the managed installation is shown
+    uv_snapshot!(context.filters(), context.python_list()
+        .arg("3.10")
+        .arg("--only-managed")
+        .env(EnvVars::UV_PYTHON_SEARCH_PATH, &bin_dir), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    cpython-3.10.[LATEST]-[PLATFORM]    managed/cpython-3.10-[PLATFORM]/[INSTALL-BIN]/[PYTHON]
+    ");
 }
 
 #[tokio::test]
@@ -744,9 +744,9 @@ fn python_pin_rm() {
     let context = uv_test::test_context_with_versions!(&["3.12"]);
 
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @"
-    exit_code: 2 (failure)
+    exit_code: 0 (success)
     ----- stderr -----
-    error: No Python version file found
+    No Python version file found
     ");
 
     // Create and remove a local pin
@@ -754,21 +754,21 @@ fn python_pin_rm() {
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @"
     exit_code: 0 (success)
     ----- stdout -----
-    Removed Python version file at `.python-version`
+    Removed Python version file at `.python-version` (pinned to `3.12`)
     ");
 
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm").arg("--global"), @"
-    exit_code: 2 (failure)
+    exit_code: 0 (success)
     ----- stderr -----
-    error: No global Python pin found
+    No global Python pin found
     ");
 
     // Global does not detect the local pin
     context.python_pin().arg("3.12").assert().success();
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm").arg("--global"), @"
-    exit_code: 2 (failure)
+    exit_code: 0 (success)
     ----- stderr -----
-    error: No global Python pin found
+    No global Python pin found
     ");
 
     context
@@ -781,7 +781,7 @@ fn python_pin_rm() {
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm").arg("--global"), @"
     exit_code: 0 (success)
     ----- stdout -----
-    Removed global Python pin at `[UV_USER_CONFIG_DIR]/.python-version`
+    Removed global Python pin at `[UV_USER_CONFIG_DIR]/.python-version` (pinned to `3.12`)
     ");
 
     // Add the global pin again
@@ -796,13 +796,20 @@ fn python_pin_rm() {
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @"
     exit_code: 0 (success)
     ----- stdout -----
-    Removed Python version file at `.python-version`
+    Removed Python version file at `.python-version` (pinned to `3.12`)
     ");
 
     // The global pin should not be removed without `--global`
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @"
-    exit_code: 2 (failure)
+    exit_code: 0 (success)
     ----- stderr -----
-    error: No Python version file found; use `--rm --global` to remove the global pin
+    No Python version file found; use `--rm --global` to remove the global pin
+    ");
+
+    // `--remove` is an alias for `--rm`
+    uv_snapshot!(context.filters(), context.python_pin().arg("--remove").arg("--global"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Removed global Python pin at `[UV_USER_CONFIG_DIR]/.python-version` (pinned to `3.12`)
     ");
 }
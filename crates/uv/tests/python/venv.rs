@@ -1254,6 +1254,108 @@ fn seed_older_python_version() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+#[test]
+#[cfg(feature = "test-pypi")]
+fn seed_upgrade_deps() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed")
+        .arg("--upgrade-deps")
+        .arg("--python")
+        .arg("3.12"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment with seed packages at: .venv
+     + pip==24.0
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    // Without `--seed`, `--upgrade-deps` has no effect.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--upgrade-deps")
+        .arg("--python")
+        .arg("3.12"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: The `--upgrade-deps` flag has no effect without `--seed`
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+}
+
+#[test]
+#[cfg(feature = "test-pypi")]
+fn without_pip() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    // `--without-pip` overrides `UV_VENV_SEED`.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--without-pip")
+        .arg("--python")
+        .arg("3.12")
+        .env(EnvVars::UV_VENV_SEED, "1"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    let site_packages = context.site_packages();
+    for name in ["pip", "setuptools", "wheel"] {
+        assert!(!site_packages.join(name).exists());
+    }
+
+    // Seed the environment, then reuse it with `--without-pip`.
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--seed")
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--allow-existing")
+        .arg("--without-pip")
+        .arg("--python")
+        .arg("3.12"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    error: The virtual environment contains seed packages, but `--without-pip` was provided: `pip`
+    "
+    );
+
+    // `--without-pip` conflicts with `--seed`.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed")
+        .arg("--without-pip"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--seed' cannot be used with '--without-pip'
+
+    Usage: uv venv --cache-dir [CACHE_DIR] --seed --exclude-newer <EXCLUDE_NEWER> <PATH>
+
+    For more information, try '--help'.
+    "
+    );
+}
+
 #[test]
 fn create_venv_with_invalid_http_timeout() {
     let context = uv_test::test_context_with_versions!(&["3.12"]).with_http_timeout("not_a_number");
@@ -1775,6 +1877,84 @@ fn verify_pyvenv_cfg_relocatable_env_var() {
     pyvenv_cfg.assert(predicates::str::contains("relocatable = true"));
 }
 
+#[test]
+fn verify_pyvenv_cfg_system_site_packages() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--system-site-packages")
+        .arg("--python")
+        .arg("3.12"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: The `--system-site-packages` flag makes system packages available at runtime, but uv will not consider them when installing packages into the environment, which may lead to conflicts
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    context
+        .venv
+        .child("pyvenv.cfg")
+        .assert(predicates::str::contains(
+            "include-system-site-packages = true",
+        ));
+}
+
+#[test]
+fn verify_pyvenv_cfg_prompt() {
+    let context = uv_test::test_context!("3.12");
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+
+    // Without `--prompt`, no prompt is written.
+    pyvenv_cfg.assert(predicates::str::contains("prompt").not());
+
+    // With `--prompt`, the prompt is written to the `pyvenv.cfg` and the activation scripts.
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--prompt")
+        .arg("my-env")
+        .assert()
+        .success();
+
+    pyvenv_cfg.assert(predicates::str::contains("prompt = my-env"));
+
+    let scripts = if cfg!(windows) {
+        context.venv.child("Scripts")
+    } else {
+        context.venv.child("bin")
+    };
+    scripts
+        .child("activate.fish")
+        .assert(predicates::str::contains("if test -n 'my-env'"));
+
+    // With `--prompt .`, the name of the current directory is used.
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--prompt")
+        .arg(".")
+        .assert()
+        .success();
+
+    let name = context
+        .temp_dir
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string();
+    pyvenv_cfg.assert(predicates::str::contains(format!("prompt = {name}")));
+}
+
 /// `--no-relocatable` takes precedence over `UV_VENV_RELOCATABLE=1`.
 #[test]
 fn no_relocatable_overrides_relocatable_env_var() {
@@ -288,6 +288,12 @@ fn publish_resolved_settings() -> anyhow::Result<()> {
         index: None,
         dry_run: false,
         no_attestations: false,
+        attestation: false,
+        sign: false,
+        identity: None,
+        verify_metadata: false,
+        list: false,
+        skip_existing: false,
         direct: false,
         publish_url: DisplaySafeUrl {
             scheme: "https",
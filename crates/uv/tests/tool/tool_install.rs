@@ -5288,6 +5288,35 @@ fn tool_install_overrides() -> Result<()> {
     Ok(())
 }
 
+/// `uv tool install pkg@latest-<date>` is not allowed, but suggests `--exclude-newer`.
+#[test]
+fn tool_install_latest_as_of() {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black@latest-2024-01-01")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `black@latest-2024-01-01` is only supported by `uv tool run`; use `uv tool install black --exclude-newer 2024-01-01` to install the latest version as of 2024-01-01
+    ");
+
+    // The extras are retained in the suggestion.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black[colorama,d]@latest-2024-01-01")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `black[colorama,d]@latest-2024-01-01` is only supported by `uv tool run`; use `uv tool install black[colorama,d] --exclude-newer 2024-01-01` to install the latest version as of 2024-01-01
+    ");
+}
+
 /// `uv tool install python` is not allowed
 #[test]
 fn tool_install_python() {
@@ -1,5 +1,6 @@
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
@@ -7,9 +8,14 @@ use assert_fs::prelude::*;
 #[cfg(unix)]
 use fs_err::{metadata, set_permissions};
 use indoc::indoc;
+use serde_json::json;
 use uv_fs::copy_dir_all;
 use uv_static::EnvVars;
 use uv_test::{uv_snapshot, venv_bin_path};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
 
 #[test]
 fn tool_run_args() {
@@ -98,7 +104,7 @@ fn tool_run_at_version() {
      + pytest==8.0.0
     ");
 
-    // Empty versions are just treated as package and command names
+    // Empty versions are rejected
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("pytest@")
         .arg("--version")
@@ -106,10 +112,7 @@ fn tool_run_at_version() {
         .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
     exit_code: 2 (failure)
     ----- stderr -----
-    error: Failed to parse: `pytest@`
-      Caused by: Expected URL
-        pytest@
-               ^
+    error: Missing version after `@` in `pytest@`; use `pytest` to omit the version
     ");
 
     // Invalid versions are just treated as package and command names
@@ -2058,6 +2061,100 @@ fn tool_run_latest_extra() {
     ");
 }
 
+/// Mount a minimal package index for `ok`, with `1.0.0` and `2.0.0` published at different times.
+async fn mount_ok_index(server: &MockServer, workspace_root: &Path) -> Result<()> {
+    let files = [
+        ("ok-1.0.0-py3-none-any.whl", "2024-01-01T00:00:00Z"),
+        ("ok-2.0.0-py3-none-any.whl", "2024-03-01T00:00:00Z"),
+    ];
+    let simple_index = json!({
+        "meta": {
+            "api-version": "1.1"
+        },
+        "name": "ok",
+        "files": files.iter().map(|(filename, upload_time)| json!({
+            "filename": filename,
+            "url": format!("{}/files/{filename}", server.uri()),
+            "hashes": {},
+            "upload-time": upload_time,
+        })).collect::<Vec<_>>(),
+    });
+    Mock::given(method("GET"))
+        .and(path("/simple/ok/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            simple_index.to_string(),
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(server)
+        .await;
+    for (filename, _) in files {
+        let wheel = fs_err::read(workspace_root.join("test/links").join(filename))?;
+        Mock::given(method("GET"))
+            .and(path(format!("/files/{filename}")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel))
+            .mount(server)
+            .await;
+    }
+    Ok(())
+}
+
+/// `uvx pkg@latest-<date>` should resolve the latest version published on or before the date.
+#[tokio::test]
+async fn tool_run_latest_as_of() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let server = MockServer::start().await;
+    mount_ok_index(&server, &context.workspace_root).await?;
+    let index_url = format!("{}/simple", server.uri());
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--index-url")
+        .arg(&index_url)
+        .arg("--from")
+        .arg("ok@latest-2024-02-01")
+        .arg("python")
+        .arg("-c")
+        .arg("import ok; print(ok.__version__)")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    1.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+
+    // Without a date, the latest version is used.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--index-url")
+        .arg(&index_url)
+        .arg("--from")
+        .arg("ok@latest")
+        .arg("python")
+        .arg("-c")
+        .arg("import ok; print(ok.__version__)")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    2.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn tool_run_extra() {
     let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
@@ -2324,6 +2421,37 @@ fn tool_run_python_at_version() {
     ");
 }
 
+/// Arguments after `python@<version>` are forwarded to the interpreter (e.g., `-m <module>`).
+#[test]
+fn tool_run_python_at_version_with_module() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12", "3.11"])
+        .with_filtered_counts()
+        .with_filtered_python_sources();
+
+    context.temp_dir.child("greet.py").write_str(indoc! {r"
+        import sys
+
+        print(f'Python {sys.version_info.major}.{sys.version_info.minor}: {sys.argv[1:]}')
+    "})?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("python@3.11")
+        .arg("-m")
+        .arg("greet")
+        .arg("--name")
+        .arg("ferris"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Python 3.11: ['--name', 'ferris']
+
+    ----- stderr -----
+    Resolved in [TIME]
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn tool_run_hint_version_not_available() {
     let context = uv_test::test_context_with_versions!(&[])
@@ -2821,6 +2949,67 @@ fn run_with_env_file() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// If a package doesn't provide a matching executable, but ships a `__main__.py`, run it as a
+/// module with `python -m`.
+#[test]
+fn tool_run_main_module() {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let foo_dir = context.temp_dir.child("foo");
+    foo_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+        })
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("__init__.py")
+        .touch()
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("__main__.py")
+        .write_str(indoc! {r#"
+        import sys
+
+        print(f"Running {__spec__.name} with {sys.argv[1:]}")
+        "#
+        })
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from")
+        .arg(foo_dir.as_os_str())
+        .arg("foo")
+        .arg("--bar")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Running foo.__main__ with ['--bar']
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+    ");
+}
+
 #[test]
 fn tool_run_from_at() {
     let context = uv_test::test_context!("3.12")